@@ -0,0 +1,30 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use eo_identifiers::Identifier;
+use std::str::FromStr;
+
+fn parse_identifiers(c: &mut Criterion) {
+    for (name, identifier) in [
+        (
+            "sentinel1",
+            "S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237",
+        ),
+        (
+            "sentinel2",
+            "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443",
+        ),
+        (
+            "sentinel3",
+            "S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002",
+        ),
+        ("landsat", "LC08_L2SP_140041_20130503_20190828_02_T1"),
+        // mostly numbers parsed by take_n_digits_in_range
+        ("modis", "MOD09GA.A2023001.h18v04.061.2023003011222.hdf"),
+    ] {
+        c.bench_function(&format!("parse {}", name), |b| {
+            b.iter(|| Identifier::from_str(black_box(identifier)).unwrap())
+        });
+    }
+}
+
+criterion_group!(benches, parse_identifiers);
+criterion_main!(benches);
@@ -0,0 +1,85 @@
+//! Process-wide cache of parsed identifiers
+//!
+//! Only successfully parsed identifiers are cached, strings failing to parse are parsed
+//! again on each call.
+use crate::{Identifier, ParseError};
+use core::str::FromStr;
+use lru::LruCache;
+use once_cell::sync::Lazy;
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+
+/// maximum number of identifiers kept in the cache of [`parse_cached`]
+///
+/// The memory used by the cache is bounded by this number of entries, each holding the
+/// input string and the parsed identifier - usually well below 1kB per entry.
+pub const CACHE_CAPACITY: usize = 256;
+
+type Cache = Mutex<LruCache<String, Identifier>>;
+
+static CACHE: Lazy<Cache> = Lazy::new(new_cache);
+
+fn new_cache() -> Cache {
+    Mutex::new(LruCache::new(
+        NonZeroUsize::new(CACHE_CAPACITY).expect("non-zero capacity"),
+    ))
+}
+
+/// parse an identifier, re-using the result of earlier calls with the same input
+///
+/// Backed by a least-recently-used cache of at most [`CACHE_CAPACITY`] entries shared by
+/// all threads. Useful when the same few strings are parsed over and over again.
+pub fn parse_cached(s: &str) -> Result<Identifier, ParseError> {
+    parse_with_cache(&CACHE, s)
+}
+
+fn parse_with_cache(cache: &Cache, s: &str) -> Result<Identifier, ParseError> {
+    if let Some(identifier) = lock_cache(cache).get(s) {
+        return Ok(identifier.clone());
+    }
+    let identifier = Identifier::from_str(s)?;
+    lock_cache(cache).put(s.to_string(), identifier.clone());
+    Ok(identifier)
+}
+
+fn lock_cache(cache: &Cache) -> std::sync::MutexGuard<'_, LruCache<String, Identifier>> {
+    // the cache holds no invariants a panicking thread could break
+    cache
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cache::{
+        lock_cache, new_cache, parse_cached, parse_with_cache, CACHE, CACHE_CAPACITY,
+    };
+    use crate::Identifier;
+    use std::str::FromStr;
+
+    #[test]
+    fn repeated_parse_is_equal() {
+        let s = "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443";
+        let first = parse_cached(s).unwrap();
+        assert_eq!(first, Identifier::from_str(s).unwrap());
+        assert!(lock_cache(&CACHE).contains(s));
+        assert_eq!(parse_cached(s).unwrap(), first);
+
+        assert!(parse_cached("not an identifier").is_err());
+        assert!(!lock_cache(&CACHE).contains("not an identifier"));
+    }
+
+    #[test]
+    fn cache_is_bounded() {
+        // a cache of its own, filling the global one would evict the entries other tests
+        // running in parallel check for
+        let cache = new_cache();
+        for day in 0..(CACHE_CAPACITY + 10) {
+            let date = chrono::NaiveDate::from_ymd_opt(2015, 1, 1).unwrap()
+                + chrono::Duration::days(day as i64);
+            let s = format!("LC08_L2SP_140041_{}_20190828_02_T1", date.format("%Y%m%d"));
+            parse_with_cache(&cache, &s).unwrap();
+        }
+        assert_eq!(lock_cache(&cache).len(), CACHE_CAPACITY);
+    }
+}
@@ -1,14 +1,16 @@
+use alloc::string::{String, ToString};
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use core::fmt::Debug;
+use core::str::FromStr;
 use nom::branch::alt;
 use nom::bytes::complete::{tag, tag_no_case, take_while, take_while_m_n};
+use nom::character::complete::char;
 use nom::character::{is_alphanumeric, is_digit};
 use nom::combinator::{map, opt};
 use nom::error::Error;
-use nom::sequence::tuple;
+use nom::sequence::{preceded, tuple};
 use nom::{Err, IResult};
-use num_traits::PrimInt;
-use std::fmt::Debug;
-use std::str::FromStr;
+use num_traits::{NumCast, PrimInt};
 
 pub(crate) fn is_char_alphanumeric(chr: char) -> bool {
     chr.is_ascii() && is_alphanumeric(chr as u8)
@@ -43,19 +45,40 @@ where
     }
 }
 
-/// taken and modified from https://github.com/badboy/iso8601/blob/main/src/parsers.rs
+/// parse exactly `n` digits into a number within `range`
+///
+/// The digits are validated and accumulated in a single pass. A non-digit character fails at
+/// its position, a number outside of the `range` fails at the position of its first digit.
 pub fn take_n_digits_in_range<T>(
     n: usize,
     range: impl core::ops::RangeBounds<T>,
 ) -> impl Fn(&str) -> IResult<&str, T>
 where
-    T: FromStr + PrimInt,
-    <T as FromStr>::Err: Debug,
+    T: PrimInt,
 {
     move |i: &str| {
-        let (new_i, number) = take_n_digits(n)(i)?;
+        let ten = <T as NumCast>::from(10).expect("10 fits into any integer type");
+        let mut number = T::zero();
+        let mut bytes = i.bytes();
+        for pos in 0..n {
+            let digit = match bytes.next() {
+                Some(b) if b.is_ascii_digit() => {
+                    <T as NumCast>::from(b - b'0').expect("a digit fits into any integer type")
+                }
+                _ => {
+                    return Err(Err::Error(Error::new(
+                        &i[pos..],
+                        nom::error::ErrorKind::TakeWhileMN,
+                    )))
+                }
+            };
+            number = number
+                .checked_mul(&ten)
+                .and_then(|number| number.checked_add(&digit))
+                .ok_or_else(|| Err::Error(Error::new(i, nom::error::ErrorKind::TooLarge)))?;
+        }
         if range.contains(&number) {
-            Ok((new_i, number))
+            Ok((&i[n..], number))
         } else {
             Err(Err::Error(Error::new(i, nom::error::ErrorKind::Eof)))
         }
@@ -117,6 +140,11 @@ fn t_separator(i: &str) -> IResult<&str, ()> {
     map(tag_no_case("t"), |_| ())(i)
 }
 
+/// UTC designator. All timestamps are UTC, so it carries no information.
+fn utc_designator(i: &str) -> IResult<&str, ()> {
+    map(tag_no_case("z"), |_| ())(i)
+}
+
 pub(crate) fn parse_simple_date(s: &str) -> IResult<&str, NaiveDate> {
     let (s_out, date_opt) = map(tuple((date_year, date_month, date_day)), |(y, m, d)| {
         NaiveDate::from_ymd_opt(y, m, d)
@@ -134,17 +162,87 @@ pub(crate) fn parse_simple_time(s: &str) -> IResult<&str, NaiveTime> {
     Ok((s_out, time))
 }
 
+/// year followed by the three digit day of the year (`YYYYDDD`)
+pub(crate) fn parse_julian_date(s: &str) -> IResult<&str, NaiveDate> {
+    let (s, year) = date_year(s)?;
+    let (s_out, day_of_year) = take_n_digits_in_range::<u32>(3, 1..=366)(s)?;
+    // day 366 of a non-leap year does not exist
+    let date = NaiveDate::from_yo_opt(year, day_of_year)
+        .ok_or_else(|| Err::Error(Error::new(s, nom::error::ErrorKind::Fail)))?;
+    Ok((s_out, date))
+}
+
 pub(crate) fn parse_esa_timestamp(s: &str) -> IResult<&str, NaiveDateTime> {
     map(
-        tuple((parse_simple_date, opt(t_separator), parse_simple_time)),
-        |(date, _, time)| NaiveDateTime::new(date, time),
+        tuple((
+            parse_simple_date,
+            opt(t_separator),
+            parse_simple_time,
+            opt(utc_designator),
+        )),
+        |(date, _, time, _)| NaiveDateTime::new(date, time),
     )(s)
 }
 
+/// format a timestamp the way `parse_esa_timestamp` expects it (`YYYYMMDDTHHMMSS`)
+pub(crate) fn format_esa_timestamp(dt: &NaiveDateTime) -> impl core::fmt::Display + '_ {
+    dt.format("%Y%m%dT%H%M%S")
+}
+
+/// extension of the directories Sentinel 1 and 2 products are distributed in
+pub(crate) const SAFE_EXTENSION: &str = "SAFE";
+
+/// extension of the directories Sentinel 3 products are distributed in
+pub(crate) const SEN3_EXTENSION: &str = "SEN3";
+
+/// optional trailing container extension, for example `.SAFE`
+///
+/// The extension is matched ignoring the case and returned in its canonical uppercase form,
+/// without the leading dot.
+pub(crate) fn parse_container_extension(
+    extension: &'static str,
+) -> impl Fn(&str) -> IResult<&str, Option<String>> {
+    move |s: &str| {
+        opt(map(preceded(char('.'), tag_no_case(extension)), |_| {
+            extension.to_string()
+        }))(s)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::common_parsers::parse_esa_timestamp;
-    use chrono::{Datelike, Timelike};
+    use crate::common_parsers::{
+        parse_container_extension, parse_esa_timestamp, parse_julian_date, take_n_digits,
+        take_n_digits_in_range, SAFE_EXTENSION,
+    };
+    use chrono::{Datelike, NaiveDate, Timelike};
+    use nom::error::{Error, ErrorKind};
+    use nom::{Err, IResult};
+
+    #[test]
+    fn parse_julian_date_day_of_year() {
+        let (_, d) = parse_julian_date("2020046").unwrap();
+        assert_eq!(d, NaiveDate::from_ymd_opt(2020, 2, 15).unwrap());
+    }
+
+    #[test]
+    fn parse_julian_date_invalid_day_of_year() {
+        assert!(parse_julian_date("2013000").is_err());
+        assert!(parse_julian_date("2013367").is_err());
+        assert!(parse_julian_date("2013400").is_err());
+        assert!(parse_julian_date("2013366").is_err());
+        let (_, d) = parse_julian_date("2020366").unwrap();
+        assert_eq!(d, NaiveDate::from_ymd_opt(2020, 12, 31).unwrap());
+    }
+
+    #[test]
+    fn container_extension() {
+        let parser = parse_container_extension(SAFE_EXTENSION);
+        assert_eq!(parser(".SAFE"), Ok(("", Some("SAFE".to_string()))));
+        assert_eq!(parser(".safe/"), Ok(("/", Some("SAFE".to_string()))));
+        assert_eq!(parser(""), Ok(("", None)));
+        assert_eq!(parser(".SEN3"), Ok((".SEN3", None)));
+    }
 
     #[test]
     fn parse_esa_timestamp_with_t() {
@@ -167,4 +265,82 @@ mod tests {
         assert_eq!(ts.minute(), 18);
         assert_eq!(ts.second(), 36);
     }
+
+    #[test]
+    fn parse_esa_timestamp_with_z() {
+        let (rest, ts) = parse_esa_timestamp("20200207T051836Z_").unwrap();
+        assert_eq!(rest, "_");
+        assert_eq!(ts, parse_esa_timestamp("20200207T051836").unwrap().1);
+        assert_eq!(ts.second(), 36);
+    }
+
+    #[test]
+    fn take_n_digits_in_range_inclusive() {
+        assert_eq!(take_n_digits_in_range(3, 1..=143)("001"), Ok(("", 1u8)));
+        assert_eq!(take_n_digits_in_range(3, 1..=143)("143"), Ok(("", 143u8)));
+        assert!(take_n_digits_in_range::<u8>(3, 1..=143)("000").is_err());
+        assert!(take_n_digits_in_range::<u8>(3, 1..=143)("144").is_err());
+    }
+
+    #[test]
+    fn take_n_digits_in_range_exclusive() {
+        assert_eq!(take_n_digits_in_range(3, 0..143)("000"), Ok(("", 0u8)));
+        assert_eq!(take_n_digits_in_range(3, 0..143)("142"), Ok(("", 142u8)));
+        assert!(take_n_digits_in_range::<u8>(3, 0..143)("143").is_err());
+    }
+
+    #[test]
+    fn take_n_digits_in_range_u64() {
+        assert_eq!(
+            take_n_digits_in_range(12, 1..=999_999_999_999)("123456789012_"),
+            Ok(("_", 123_456_789_012u64))
+        );
+        assert!(take_n_digits_in_range::<u64>(12, 1..=999_999_999_999)("000000000000").is_err());
+    }
+
+    /// the former two-pass implementation, used as reference
+    fn take_n_digits_in_range_reference(
+        n: usize,
+        range: impl core::ops::RangeBounds<u16>,
+    ) -> impl Fn(&str) -> IResult<&str, u16> {
+        move |i: &str| {
+            let (new_i, number) = take_n_digits(n)(i)?;
+            if range.contains(&number) {
+                Ok((new_i, number))
+            } else {
+                Err(Err::Error(Error::new(i, ErrorKind::Eof)))
+            }
+        }
+    }
+
+    #[test]
+    fn take_n_digits_in_range_matches_reference() {
+        for input in [
+            "", "0", "00", "001", "0010", "143", "144", "999", "12a", "a12", "1_3", "-12", "ä12",
+            "12ä", "0421_X",
+        ] {
+            for n in 1..=3 {
+                let expected = take_n_digits_in_range_reference(n, 1..=143)(input);
+                let result = take_n_digits_in_range(n, 1..=143)(input);
+                assert_eq!(result.is_ok(), expected.is_ok(), "{} with n={}", input, n);
+                if let Ok(expected) = expected {
+                    assert_eq!(result.unwrap(), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn take_n_digits_in_range_error_position() {
+        assert_eq!(
+            take_n_digits_in_range::<u8>(3, 0..=143)("12a"),
+            Err(Err::Error(Error::new("a", ErrorKind::TakeWhileMN)))
+        );
+        assert_eq!(
+            take_n_digits_in_range::<u8>(3, 0..=143)("144"),
+            Err(Err::Error(Error::new("144", ErrorKind::Eof)))
+        );
+        // overflowing the integer type fails instead of panicking
+        assert!(take_n_digits_in_range::<u8>(3, 0..=143)("999").is_err());
+    }
 }
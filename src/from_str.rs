@@ -1,88 +1,515 @@
+use crate::common_parsers::{
+    parse_julian_date, parse_simple_date, parse_simple_time, SAFE_EXTENSION, SEN3_EXTENSION,
+};
 use crate::identifiers;
-use crate::Identifier;
+use crate::{Identifier, Mission};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use chrono::{NaiveDate, NaiveDateTime};
+use core::fmt;
+use core::str::FromStr;
 use nom::{IResult, Needed};
+#[cfg(feature = "std")]
+use std::path::Path;
 
-#[derive(thiserror::Error, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub enum ParseError {
-    #[error("not enough data")]
     NotEnoughData(usize),
 
-    #[error("parse error at position {0}")]
-    FailedAtPosition(usize),
+    FailedAtPosition {
+        /// byte position in the input where parsing failed
+        pos: usize,
+
+        /// the input around the failure, up to 8 characters on each side
+        near: String,
+
+        /// byte position of the failure within `near`
+        near_offset: usize,
+    },
+
+    NoIdentifier,
 }
 
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::NotEnoughData(_) => f.write_str("not enough data"),
+            ParseError::FailedAtPosition {
+                pos,
+                near,
+                near_offset,
+            } => write!(
+                f,
+                "parse error at position {}\n{}",
+                pos,
+                snippet(near, *near_offset)
+            ),
+            ParseError::NoIdentifier => f.write_str("no identifier found"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
 impl ParseError {
-    pub(crate) fn error_pos(&self) -> usize {
+    /// byte position in the input where parsing failed, if known
+    pub fn error_pos(&self) -> Option<usize> {
         match self {
-            ParseError::NotEnoughData(p) => *p,
-            ParseError::FailedAtPosition(p) => *p,
+            ParseError::FailedAtPosition { pos, .. } => Some(*pos),
+            ParseError::NotEnoughData(_) | ParseError::NoIdentifier => None,
+        }
+    }
+
+    /// failure at the byte position `pos` of `input`
+    pub(crate) fn failed_at(input: &str, pos: usize) -> Self {
+        let start = input[..pos]
+            .char_indices()
+            .rev()
+            .take(SNIPPET_RADIUS)
+            .last()
+            .map_or(pos, |(i, _)| i);
+        let end = input[pos..]
+            .char_indices()
+            .nth(SNIPPET_RADIUS)
+            .map_or(input.len(), |(i, _)| pos + i);
+        ParseError::FailedAtPosition {
+            pos,
+            near: input[start..end].to_string(),
+            near_offset: pos - start,
         }
     }
 }
 
-pub(crate) fn map_parser<P, O>(p: P) -> impl FnMut(&str) -> Result<O, ParseError>
+/// number of characters of the input shown on each side of a failure
+const SNIPPET_RADIUS: usize = 8;
+
+/// the input around a failure with a caret pointing at it
+fn snippet(near: &str, near_offset: usize) -> String {
+    format!(
+        "  | {}\n  | {}^",
+        near,
+        " ".repeat(near[..near_offset].chars().count())
+    )
+}
+
+/// failure of a parser, only tracking the position to avoid allocating for failures
+/// which are discarded
+#[derive(Debug, Clone, Copy)]
+enum Failure {
+    NotEnoughData(usize),
+    FailedAtPosition(usize),
+}
+
+impl Failure {
+    fn pos(&self) -> usize {
+        match self {
+            Failure::NotEnoughData(p) | Failure::FailedAtPosition(p) => *p,
+        }
+    }
+
+    /// move the position of the failure by `offset` bytes, used when the parser was applied
+    /// to a part of the input starting at `offset`
+    fn shifted(self, offset: usize) -> Self {
+        match self {
+            Failure::FailedAtPosition(pos) => Failure::FailedAtPosition(pos + offset),
+            not_enough_data => not_enough_data,
+        }
+    }
+
+    fn into_parse_error(self, input: &str) -> ParseError {
+        match self {
+            Failure::NotEnoughData(p) => ParseError::NotEnoughData(p),
+            Failure::FailedAtPosition(pos) => ParseError::failed_at(input, pos),
+        }
+    }
+}
+
+fn run_parser<P, O>(p: P, s: &str) -> Result<O, Failure>
 where
     P: Fn(&str) -> IResult<&str, O>,
 {
-    move |s: &str| match p(s) {
+    match p(s) {
         Ok((_, v)) => Ok(v),
         Err(e) => Err(match e {
-            nom::Err::Incomplete(needed) => ParseError::NotEnoughData(match needed {
+            nom::Err::Incomplete(needed) => Failure::NotEnoughData(match needed {
                 Needed::Unknown => 0,
                 Needed::Size(p) => p.get(),
             }),
-            nom::Err::Error(e) => ParseError::FailedAtPosition(s.len() - e.input.len()),
-            nom::Err::Failure(e) => ParseError::FailedAtPosition(s.len() - e.input.len()),
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                Failure::FailedAtPosition(s.len() - e.input.len())
+            }
         }),
     }
 }
 
+pub(crate) fn map_parser<P, O>(p: P) -> impl FnMut(&str) -> Result<O, ParseError>
+where
+    P: Fn(&str) -> IResult<&str, O>,
+{
+    move |s: &str| run_parser(&p, s).map_err(|failure| failure.into_parse_error(s))
+}
+
 #[macro_export]
 macro_rules! impl_from_str {
     ($parser_fn:ident, $out:ty) => {
-        impl std::str::FromStr for $out {
+        impl core::str::FromStr for $out {
             type Err = $crate::ParseError;
 
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                crate::from_str::map_parser($parser_fn)(s).map(|v| v.into())
+                $crate::from_str::map_parser($parser_fn)(s).map(|v| v.into())
             }
         }
     };
 }
 
-impl std::str::FromStr for Identifier {
+/// implement `Serialize` and `Deserialize` through the `Display` and `FromStr`
+/// implementations of a type, when the `serde` feature is enabled
+///
+/// Delegates to [`crate::serde_str`].
+macro_rules! impl_serde_via_str {
+    ($ty:ty) => {
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $ty {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $crate::serde_str::serialize(self, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $ty {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $crate::serde_str::deserialize(deserializer)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_serde_via_str;
+
+type IdentifierParser = fn(&str) -> Result<Identifier, Failure>;
+
+/// name of the parser, the parser and an example of an identifier accepted by it
+type IdentifierParserEntry = (&'static str, IdentifierParser, &'static str);
+
+macro_rules! identifier_parser {
+    ($module:ident :: $parser_fn:ident, $example:literal) => {
+        (
+            concat!(stringify!($module), "::", stringify!($parser_fn)),
+            |s: &str| run_parser(identifiers::$module::$parser_fn, s).map(Identifier::from),
+            $example,
+        )
+    };
+}
+
+/// all parsers tried by `Identifier::from_str`, in the order they are tried.
+const IDENTIFIER_PARSERS: &[IdentifierParserEntry] = &[
+    identifier_parser!(
+        sentinel1::parse_product,
+        "S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237"
+    ),
+    identifier_parser!(
+        sentinel2::parse_product,
+        "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443"
+    ),
+    identifier_parser!(
+        sentinel2::parse_product_long,
+        "S2A_OPER_PRD_MSIL1C_PDMC_20160113T172424_R051_V20160113T104035_20160113T104035"
+    ),
+    identifier_parser!(
+        sentinel3::parse_product,
+        "S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002"
+    ),
+    identifier_parser!(
+        sentinel5p::parse_product,
+        "S5P_OFFL_L2__NO2____20230101T005953_20230101T024123_21890_03_020400_20230102T170000"
+    ),
+    // Landsat products are tried before the older scene ids. Products always have a
+    // `0` before the satellite number (`LC08_...`) which the scene id parser rejects
+    // as satellite numbers start at 1, so a product - even a truncated one - never
+    // ends up as a scene id.
+    identifier_parser!(
+        landsat::parse_product,
+        "LC08_L2SP_140041_20130503_20190828_02_T1"
+    ),
+    identifier_parser!(landsat::parse_scene_id, "LC80390222013076EDC00"),
+    identifier_parser!(
+        sentinel1::parse_dataset,
+        "s1a-iw-grd-vh-20221029t171425-20221029t171450-045660-0575ce-002"
+    ),
+    identifier_parser!(hls::parse_product, "HLS.S30.T53NMJ.2017005T013442.v2.0"),
+    identifier_parser!(
+        modis::parse_product,
+        "MOD09GA.A2023001.h18v04.061.2023003011222"
+    ),
+    identifier_parser!(planet::parse_scene, "20210602_143854_1003_3B_AnalyticMS"),
+];
+
+/// marker suffixes some tools leave on object keys in cloud storage
+const CLOUD_ARTIFACTS: &[&str] = &["_$folder$"];
+
+fn strip_cloud_artifacts(s: &str) -> &str {
+    CLOUD_ARTIFACTS
+        .iter()
+        .find_map(|artifact| s.strip_suffix(artifact))
+        .unwrap_or(s)
+}
+
+/// extensions of the directories ESA products are distributed in
+const CONTAINER_EXTENSIONS: [&str; 2] = [SAFE_EXTENSION, SEN3_EXTENSION];
+
+/// strip a trailing `.SAFE` or `.SEN3` extension, ignoring the case
+fn strip_container_extension(s: &str) -> &str {
+    CONTAINER_EXTENSIONS
+        .iter()
+        .find_map(|extension| {
+            let pos = s.len().checked_sub(extension.len() + 1)?;
+            (s.is_char_boundary(pos)
+                && s[pos..].starts_with('.')
+                && s[pos + 1..].eq_ignore_ascii_case(extension))
+            .then(|| &s[..pos])
+        })
+        .unwrap_or(s)
+}
+
+impl core::str::FromStr for Identifier {
     type Err = ParseError;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut closest_e = ParseError::NotEnoughData(0);
-
-        macro_rules! try_parser {
-            ($p:expr) => {
-                match map_parser($p)(s) {
-                    Ok(v) => return Ok(v.into()),
-                    Err(e) => {
-                        if e.error_pos() > closest_e.error_pos() {
-                            closest_e = e;
-                        }
+    /// Surrounding whitespace - for example from copy-pasting - is ignored. The `.SAFE` and
+    /// `.SEN3` extensions are removed before parsing, so the name of a product and the name of
+    /// its directory result in equal identifiers.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let trimmed = input.trim_start();
+        // only suffixes are stripped besides the leading whitespace
+        let offset = input.len() - trimmed.len();
+        let s = strip_container_extension(strip_cloud_artifacts(trimmed.trim_end()));
+        let mut closest_e = Failure::NotEnoughData(0);
+
+        for (_, parser, _) in IDENTIFIER_PARSERS {
+            match parser(s) {
+                Ok(v) => return Ok(v),
+                Err(e) => {
+                    if e.pos() > closest_e.pos() {
+                        closest_e = e;
                     }
-                };
+                }
+            }
+        }
+
+        // the snippet of the input is only built for the failure which is returned
+        Err(closest_e.shifted(offset).into_parse_error(input))
+    }
+}
+
+/// Parts of an identifier which could be determined, even when parsing the
+/// complete identifier failed
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct PartialIdentifier {
+    /// mission, determined from the prefix of the identifier
+    pub mission: Option<Mission>,
+
+    /// acquisition date
+    pub acquire_date: Option<NaiveDate>,
+
+    /// the complete identifier, when parsing succeeded
+    pub identifier: Option<Identifier>,
+}
+
+/// mission and position of the acquisition date, determined from the prefix of an identifier
+struct Sniffed<'a> {
+    mission: Mission,
+    date_token: Option<&'a str>,
+    julian_date: bool,
+}
+
+fn sniff(s: &str) -> Option<Sniffed<'_>> {
+    let prefix = s.get(..4)?.to_ascii_uppercase();
+    let landsat = |digit: u8| match digit {
+        b'1'..=b'9' => Some(identifiers::landsat::MissionId::from(digit - b'0').into()),
+        _ => None,
+    };
+    let (mission, date_token, julian_date) = match *prefix.as_bytes() {
+        [b'S', b'1', _, b'-'] => (Mission::Sentinel1, s.split('-').nth(4), false),
+        [b'S', b'1', _, b'_'] => (Mission::Sentinel1, s.get(17..), false),
+        [b'S', b'2', _, b'_'] => match s.get(4..8).map(|class| class.to_ascii_uppercase()) {
+            // legacy long naming convention, the date of the validity start
+            Some(class) if class == "OPER" || class == "TEST" => {
+                (Mission::Sentinel2, s.get(47..), false)
+            }
+            _ => (Mission::Sentinel2, s.get(11..), false),
+        },
+        [b'S', b'3', _, b'_'] => (Mission::Sentinel3, s.get(16..), false),
+        [b'S', b'5', b'P', b'_'] => (Mission::Sentinel5p, s.get(20..), false),
+        [b'H', b'L', b'S', b'.'] => (Mission::Hls, s.split('.').nth(3), true),
+        [b'M', b'O' | b'Y' | b'C', b'D', _] => (
+            Mission::Modis,
+            s.split('.').nth(1).and_then(|token| token.get(1..)),
+            true,
+        ),
+        [b'1' | b'2', b'0'..=b'9', b'0'..=b'9', b'0'..=b'9'] => (Mission::Planet, Some(s), false),
+        [b'L', _, b'0', digit] => (landsat(digit)?, s.split('_').nth(3), false),
+        [b'L', _, digit, _] => (landsat(digit)?, s.get(9..), true),
+        _ => return None,
+    };
+    Some(Sniffed {
+        mission,
+        date_token,
+        julian_date,
+    })
+}
+
+impl Identifier {
+    /// Extract the acquisition date of an identifier without parsing it completely
+    ///
+    /// The mission is determined from the prefix of the identifier and only the date at the
+    /// position given by the naming convention of the mission is parsed. No other parts of the
+    /// identifier are validated, so this is only intended for quickly filtering large listings
+    /// by date.
+    pub fn peek_date(s: &str) -> Option<NaiveDate> {
+        let sniffed = sniff(s)?;
+        let token = sniffed.date_token?;
+        let parsed = if sniffed.julian_date {
+            parse_julian_date(token)
+        } else {
+            parse_simple_date(token)
+        };
+        parsed.ok().map(|(_, date)| date)
+    }
+
+    /// Scan a string for the first `YYYYMMDD[THHMMSS]` token, without identifying the mission
+    ///
+    /// This is a heuristic for quickly pre-filtering large listings of object keys by date
+    /// before parsing them completely. The first run of exactly eight digits forming a valid
+    /// date is returned, together with the time when it is followed by `T` and six digits -
+    /// otherwise the time is midnight. Julian dates - as used by HLS, MODIS and Landsat scene
+    /// ids - are not recognized and for most missions this is the sensing start, but this is
+    /// not guaranteed for arbitrary keys.
+    pub fn parse_relaxed_datetime_only(s: &str) -> Option<NaiveDateTime> {
+        let bytes = s.as_bytes();
+        let mut pos = 0;
+        while pos < bytes.len() {
+            let digits = bytes[pos..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count();
+            if digits == 8 {
+                if let Ok((rest, date)) = parse_simple_date(&s[pos..]) {
+                    let time = rest
+                        .strip_prefix(['T', 't'])
+                        .filter(|time| time.bytes().take_while(u8::is_ascii_digit).count() == 6)
+                        .and_then(|time| parse_simple_time(time).ok())
+                        .map(|(_, time)| time)
+                        .unwrap_or_default();
+                    return Some(NaiveDateTime::new(date, time));
+                }
+            }
+            pos += digits.max(1);
+        }
+        None
+    }
+
+    /// Run all parsers on the given string and return the outcome of each of them
+    ///
+    /// The parsers are identified by their name, for example `sentinel2::parse_product`.
+    /// This is a debugging aid to find out why a string could not be parsed.
+    pub fn try_all(s: &str) -> Vec<(&'static str, Result<Identifier, ParseError>)> {
+        IDENTIFIER_PARSERS
+            .iter()
+            .map(|(name, parser, _)| (*name, parser(s).map_err(|e| e.into_parse_error(s))))
+            .collect()
+    }
+
+    /// Missions of which identifiers may start with the given prefix
+    ///
+    /// Intended for autocompletion. The prefix is completed with the remainder of an example
+    /// identifier of each parser and the missions of all completions which can be parsed are
+    /// returned, in the order the parsers are tried by `Identifier::from_str`. This is an
+    /// approximation: when the prefix already extends beyond a field of variable length, the
+    /// completion is misaligned and the mission is missed, unless the prefix itself is a
+    /// complete identifier.
+    pub fn candidate_missions(prefix: &str) -> Vec<Mission> {
+        let mut missions = Vec::new();
+        for (_, parser, example) in IDENTIFIER_PARSERS {
+            let completed = match example.get(prefix.len()..) {
+                Some(remainder) => format!("{}{}", prefix, remainder),
+                None => prefix.to_string(),
             };
+            if let Ok(identifier) = parser(&completed).or_else(|_| parser(prefix)) {
+                let mission = identifier.mission();
+                if !missions.contains(&mission) {
+                    missions.push(mission);
+                }
+            }
         }
+        missions
+    }
 
-        try_parser!(identifiers::sentinel1::parse_product);
-        try_parser!(identifiers::sentinel2::parse_product);
-        try_parser!(identifiers::sentinel3::parse_product);
-        try_parser!(identifiers::landsat::parse_product);
-        try_parser!(identifiers::landsat::parse_scene_id);
-        try_parser!(identifiers::sentinel1::parse_dataset);
+    /// Parse many identifiers at once
+    ///
+    /// The results are returned in the order of the input, so failures can be matched to
+    /// the strings they originate from.
+    pub fn parse_many<'a, I: IntoIterator<Item = &'a str>>(
+        iter: I,
+    ) -> Vec<Result<Identifier, ParseError>> {
+        iter.into_iter().map(Identifier::from_str).collect()
+    }
 
-        Err(closest_e)
+    /// Parse many identifiers at once, dropping all strings which could not be parsed
+    pub fn parse_many_ok<'a, I: IntoIterator<Item = &'a str>>(iter: I) -> Vec<Identifier> {
+        iter.into_iter()
+            .filter_map(|s| Identifier::from_str(s).ok())
+            .collect()
+    }
+
+    /// Parse the identifier from the last component of a path
+    ///
+    /// Both `/` and `\` are accepted as separators independent of the platform, so
+    /// Windows paths can be handled everywhere. Trailing separators - as common for
+    /// `.SAFE` directories - and folder markers of cloud storage (`_$folder$`) are ignored.
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Identifier, ParseError> {
+        let path = path.as_ref().to_string_lossy();
+        let basename = strip_cloud_artifacts(&path)
+            .trim_end_matches(['/', '\\'])
+            .rsplit(['/', '\\'])
+            .next()
+            .unwrap_or_default();
+        if basename.is_empty() {
+            return Err(ParseError::NoIdentifier);
+        }
+        Identifier::from_str(basename)
+    }
+
+    /// Parse as much of an identifier as possible
+    ///
+    /// Useful for triaging malformed identifiers.
+    pub fn partial(s: &str) -> PartialIdentifier {
+        match Identifier::from_str(s) {
+            Ok(identifier) => PartialIdentifier {
+                mission: Some(identifier.mission()),
+                acquire_date: Some(identifier.start_datetime().date()),
+                identifier: Some(identifier),
+            },
+            Err(_) => PartialIdentifier {
+                mission: sniff(s).map(|sniffed| sniffed.mission),
+                acquire_date: Identifier::peek_date(s),
+                identifier: None,
+            },
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::Identifier;
+    use crate::from_str::{strip_container_extension, IDENTIFIER_PARSERS};
+    use crate::identifiers::sentinel2;
+    use crate::{Identifier, Mission, ParseError};
+    use chrono::NaiveDate;
+    #[cfg(feature = "std")]
+    use std::path::PathBuf;
     use std::str::FromStr;
 
     #[test]
@@ -92,4 +519,357 @@ mod test {
                 .unwrap();
         assert!(matches!(ident, Identifier::Sentinel2Product(_)));
     }
+
+    #[test]
+    fn test_parse_error_snippet() {
+        let err = sentinel2::Product::from_str(
+            "S2A_MSIL9C_20170105T013442_N0204_R031_T53NMJ_20170105T013443",
+        )
+        .unwrap_err();
+        assert_eq!(err.error_pos(), Some(7));
+        assert!(matches!(
+            &err,
+            ParseError::FailedAtPosition { pos: 7, near, near_offset: 7 } if near == "S2A_MSIL9C_2017"
+        ));
+        assert_eq!(
+            err.to_string(),
+            "parse error at position 7\n  | S2A_MSIL9C_2017\n  |        ^"
+        );
+
+        let err = sentinel2::Product::from_str(
+            "S2A_MSIL1C_20170105T013442_N0204_R0X1_T53NMJ_20170105T013443",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at position 35\n  | N0204_R0X1_T53NM\n  |         ^"
+        );
+
+        // the closest failure of all parsers tried
+        let err =
+            Identifier::from_str("S2A_MSIL1C_20170105T013442_N0204_R0X1_T53NMJ_20170105T013443")
+                .unwrap_err();
+        assert_eq!(err.error_pos(), Some(35));
+        assert!(matches!(
+            &err,
+            ParseError::FailedAtPosition { pos: 35, near, .. } if near == "N0204_R0X1_T53NM"
+        ));
+        assert_eq!(ParseError::NoIdentifier.error_pos(), None);
+    }
+
+    #[test]
+    fn test_parse_error_position_with_leading_whitespace() {
+        let err = Identifier::from_str(
+            "  S2A_MSIL1C_20170105T013442_N0204_R0X1_T53NMJ_20170105T013443.SAFE ",
+        )
+        .unwrap_err();
+        assert_eq!(err.error_pos(), Some(37));
+        assert_eq!(
+            err.to_string(),
+            "parse error at position 37\n  | N0204_R0X1_T53NM\n  |         ^"
+        );
+    }
+
+    #[test]
+    fn test_parse_many() {
+        let inputs = [
+            "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443",
+            "not an identifier",
+            "LC08_L2SP_140041_20130503_20190828_02_T1",
+            "S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002",
+            "",
+        ];
+        let results = Identifier::parse_many(inputs);
+        assert_eq!(results.len(), inputs.len());
+        assert!(matches!(results[0], Ok(Identifier::Sentinel2Product(_))));
+        assert!(results[1].is_err());
+        assert!(matches!(results[2], Ok(Identifier::LandsatProduct(_))));
+        assert!(matches!(results[3], Ok(Identifier::Sentinel3Product(_))));
+        assert!(results[4].is_err());
+
+        let identifiers = Identifier::parse_many_ok(inputs);
+        assert_eq!(identifiers.len(), 3);
+        assert!(matches!(identifiers[0], Identifier::Sentinel2Product(_)));
+        assert!(matches!(identifiers[1], Identifier::LandsatProduct(_)));
+        assert!(matches!(identifiers[2], Identifier::Sentinel3Product(_)));
+    }
+
+    #[test]
+    fn test_identifier_from_str_surrounding_whitespace() {
+        let name = "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443";
+        let ident = Identifier::from_str(name).unwrap();
+        for s in [
+            format!("  {}  ", name),
+            format!("\t{}\n", name),
+            format!(" {}.SAFE\r\n", name),
+        ] {
+            assert_eq!(Identifier::from_str(&s).unwrap(), ident);
+        }
+        assert!(Identifier::from_str(
+            "S2A_MSIL1C_20170105T013442_N0204_R031 _T53NMJ_20170105T013443"
+        )
+        .is_err());
+        assert!(Identifier::from_str(
+            "S2A_MSIL1C 20170105T013442_N0204_R031_T53NMJ_20170105T013443"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_identifier_from_str_cloud_artifacts() {
+        let ident = Identifier::from_str(
+            "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443.SAFE_$folder$",
+        )
+        .unwrap();
+        assert!(matches!(ident, Identifier::Sentinel2Product(_)));
+    }
+
+    #[test]
+    fn test_identifier_from_str_landsat_precedence() {
+        let ident = Identifier::from_str("LC08_L1GT_029030_20151209_20160131_01").unwrap();
+        assert!(matches!(ident, Identifier::LandsatProduct(_)));
+
+        let ident = Identifier::from_str("LC80390222013076EDC00").unwrap();
+        assert!(matches!(ident, Identifier::LandsatSceneId(_)));
+
+        // truncated products
+        assert!(Identifier::from_str("LC08_L1GT_029030_20151209").is_err());
+        assert!(Identifier::from_str("LC08_L1GT_029030").is_err());
+        assert!(Identifier::from_str("LC08").is_err());
+        assert!(Identifier::from_str("LC0803902220130").is_err());
+    }
+
+    #[test]
+    fn test_identifier_from_str_case_insensitive() {
+        for s in [
+            "S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237",
+            "S1A-IW-GRD-VH-20221029T171425-20221029T171450-045660-0575CE-002",
+            "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443",
+            "S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002",
+            "LC08_L2SP_140041_20130503_20190828_02_T1",
+            "LC80390222013076EDC00",
+            "HLS.S30.T53NMJ.2017005T013442.v2.0",
+            "MOD09GA.A2023001.h18v04.061.2023003011222.hdf",
+            "S5P_OFFL_L2__NO2____20230101T005953_20230101T024123_21890_03_020400_20230102T170000",
+            "S2A_OPER_PRD_MSIL1C_PDMC_20160113T172424_R051_V20160113T104035_20160113T104035",
+        ] {
+            assert_eq!(
+                Identifier::from_str(&s.to_lowercase()).unwrap(),
+                Identifier::from_str(s).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_relaxed_datetime_only() {
+        let dt = |s: &str| {
+            Identifier::parse_relaxed_datetime_only(s)
+                .map(|dt| dt.format("%Y%m%dT%H%M%S").to_string())
+        };
+        for (s, expected) in [
+            (
+                "s1/S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237.zip",
+                "20200207T051836",
+            ),
+            (
+                "s1a-iw-grd-vh-20221029t171425-20221029t171450-045660-0575ce-002.tiff",
+                "20221029T171425",
+            ),
+            (
+                "tiles/53/N/MJ/S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443.SAFE",
+                "20170105T013442",
+            ),
+            (
+                "S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002",
+                "20220801T210143",
+            ),
+            (
+                "S5P_OFFL_L2__NO2____20230101T005953_20230101T024123_21890_03_020400_20230102T170000.nc",
+                "20230101T005953",
+            ),
+            (
+                "collection02/level-2/LC08_L2SP_140041_20130503_20190828_02_T1_MTL.txt",
+                "20130503T000000",
+            ),
+            ("20210602_143854_1003_3B_AnalyticMS.tif", "20210602T000000"),
+        ] {
+            assert_eq!(dt(s).as_deref(), Some(expected), "{}", s);
+        }
+
+        // julian dates and digit runs of other lengths are not recognized
+        assert_eq!(dt("LC80390222013076EDC00"), None);
+        assert_eq!(dt("HLS.S30.T53NMJ.2017005T013442.v2.0"), None);
+        assert_eq!(dt("no date 123456789"), None);
+    }
+
+    #[test]
+    fn test_peek_date() {
+        for (s, date) in [
+            (
+                "S1A_IW_RAW__0SDV_20200207T051836_20200207T051901_031142_039466_A237",
+                (2020, 2, 7),
+            ),
+            (
+                "s1a-iw1-slc-vh-20221029t171425-20221029t171450-045660-0575ce-002",
+                (2022, 10, 29),
+            ),
+            (
+                "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443",
+                (2017, 1, 5),
+            ),
+            (
+                "S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002",
+                (2022, 8, 1),
+            ),
+            ("LC08_L2SP_140041_20130503_20190828_02_T1", (2013, 5, 3)),
+            ("LC80390222013076EDC00", (2013, 3, 17)),
+            ("HLS.S30.T53NMJ.2017005T013442.v2.0", (2017, 1, 5)),
+            ("MOD09GA.A2023001.h18v04.061.2023003011222.hdf", (2023, 1, 1)),
+            (
+                "S5P_OFFL_L2__NO2____20230101T005953_20230101T024123_21890_03_020400_20230102T170000",
+                (2023, 1, 1),
+            ),
+            ("20210602_143854_1003_3B_AnalyticMS", (2021, 6, 2)),
+            (
+                "S2A_OPER_PRD_MSIL1C_PDMC_20160113T172424_R051_V20160113T104035_20160113T104035.SAFE",
+                (2016, 1, 13),
+            ),
+        ] {
+            assert_eq!(
+                Identifier::peek_date(s),
+                NaiveDate::from_ymd_opt(date.0, date.1, date.2)
+            );
+        }
+        assert_eq!(Identifier::peek_date("S2A"), None);
+        assert_eq!(Identifier::peek_date("something else"), None);
+    }
+
+    #[test]
+    fn test_try_all() {
+        let results =
+            Identifier::try_all("S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443");
+        assert_eq!(results.len(), IDENTIFIER_PARSERS.len());
+        for (name, result) in results {
+            assert_eq!(result.is_ok(), name == "sentinel2::parse_product");
+        }
+    }
+
+    #[test]
+    fn test_parser_examples() {
+        for (name, parser, example) in IDENTIFIER_PARSERS {
+            assert!(parser(example).is_ok(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_candidate_missions() {
+        assert_eq!(
+            Identifier::candidate_missions("S2"),
+            vec![Mission::Sentinel2]
+        );
+        assert_eq!(
+            Identifier::candidate_missions("LC08"),
+            vec![Mission::Landsat8]
+        );
+        assert_eq!(
+            Identifier::candidate_missions("le07_l2"),
+            vec![Mission::Landsat7]
+        );
+        assert_eq!(
+            Identifier::candidate_missions("S3A_OL"),
+            vec![Mission::Sentinel3]
+        );
+        assert_eq!(
+            Identifier::candidate_missions("S"),
+            vec![
+                Mission::Sentinel1,
+                Mission::Sentinel2,
+                Mission::Sentinel3,
+                Mission::Sentinel5p
+            ]
+        );
+        assert_eq!(
+            Identifier::candidate_missions("S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ"),
+            vec![Mission::Sentinel2]
+        );
+        assert!(Identifier::candidate_missions("S9").is_empty());
+        assert!(Identifier::candidate_missions("something else").is_empty());
+    }
+
+    #[test]
+    fn test_partial() {
+        let partial = Identifier::partial("S2A_MSIL1C_20170105T013442_N0204_R0");
+        assert_eq!(partial.mission, Some(Mission::Sentinel2));
+        assert_eq!(partial.acquire_date, NaiveDate::from_ymd_opt(2017, 1, 5));
+        assert_eq!(partial.identifier, None);
+
+        let partial = Identifier::partial("LC08_L2SP_140041_20130503_20190828_02_T1");
+        assert_eq!(partial.mission, Some(Mission::Landsat8));
+        assert!(partial.identifier.is_some());
+
+        let partial = Identifier::partial("XYZ");
+        assert_eq!(partial.mission, None);
+        assert_eq!(partial.acquire_date, None);
+    }
+
+    #[test]
+    fn test_safe_extension_is_normalized() {
+        let name = "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443";
+        let ident = Identifier::from_str(name).unwrap();
+        for s in [format!("{}.SAFE", name), format!("{}.safe", name)] {
+            assert_eq!(Identifier::from_str(&s).unwrap(), ident);
+        }
+        assert_eq!(strip_container_extension(&format!("{}.SAFE", name)), name);
+        assert_eq!(strip_container_extension(name), name);
+        assert_eq!(strip_container_extension("ä.SAFE"), "ä");
+        assert_eq!(strip_container_extension("äSAFE"), "äSAFE");
+        assert_eq!(strip_container_extension("ä.sen3"), "ä");
+        assert_eq!(strip_container_extension("äSEN3"), "äSEN3");
+
+        let name = "S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002";
+        let ident = Identifier::from_str(name).unwrap();
+        assert_eq!(
+            Identifier::from_str(&format!("{}.SEN3", name)).unwrap(),
+            ident
+        );
+        assert_eq!(ident.to_string(), name);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_path() {
+        for path in [
+            "/data/S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443.SAFE",
+            "/data/S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443.SAFE/",
+            "C:\\data\\S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443.SAFE",
+            "C:\\data\\S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443.SAFE\\",
+            "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443",
+        ] {
+            let ident = Identifier::from_path(PathBuf::from(path)).unwrap();
+            assert!(matches!(ident, Identifier::Sentinel2Product(_)));
+        }
+        assert!(matches!(
+            Identifier::from_path(PathBuf::from(
+                "s3://bucket/LC08_L2SP_140041_20130503_20190828_02_T1_$folder$"
+            )),
+            Ok(Identifier::LandsatProduct(_))
+        ));
+        assert!(matches!(
+            Identifier::from_path(PathBuf::from("/")),
+            Err(ParseError::NoIdentifier)
+        ));
+    }
+
+    #[test]
+    fn test_identifier_from_str_modis() {
+        let ident = Identifier::from_str("MOD09GA.A2023001.h18v04.061.2023003011222.hdf").unwrap();
+        assert!(matches!(ident, Identifier::ModisProduct(_)));
+        assert_eq!(ident.mission(), Mission::Modis);
+    }
+
+    #[test]
+    fn test_identifier_from_str_hls() {
+        let ident = Identifier::from_str("HLS.S30.T53NMJ.2017005T013442.v2.0").unwrap();
+        assert!(matches!(ident, Identifier::HlsProduct(_)));
+    }
 }
@@ -0,0 +1,180 @@
+//! Harmonized Landsat Sentinel-2 (HLS)
+//!
+//! [naming convention](https://lpdaac.usgs.gov/data/get-started-data/collection-overview/missions/harmonized-landsat-sentinel-2-hls-overview/)
+//!
+//! # Example
+//!
+//! ```rust
+//! use eo_identifiers::identifiers::hls::Product;
+//! use std::str::FromStr;
+//!
+//! assert!(
+//!     Product::from_str("HLS.S30.T53NMJ.2017005T013442.v2.0")
+//!     .is_ok()
+//! );
+//! ```
+use crate::common_parsers::{
+    parse_julian_date, parse_simple_time, take_alphanumeric_n, take_n_digits,
+};
+use crate::{impl_from_str, Mission};
+use alloc::string::String;
+use chrono::NaiveDateTime;
+use core::fmt;
+use nom::branch::alt;
+use nom::bytes::complete::tag_no_case;
+use nom::character::complete::char;
+use nom::combinator::map;
+use nom::IResult;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SubProduct {
+    /// S30 = Sentinel-2 MSI derived surface reflectance
+    S30,
+
+    /// L30 = Landsat OLI derived surface reflectance
+    L30,
+}
+
+/// HLS product
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Product {
+    /// sub product
+    pub sub_product: SubProduct,
+
+    /// MGRS tile number
+    pub tile_number: String,
+
+    /// acquisition datetime
+    pub start_datetime: NaiveDateTime,
+
+    /// major and minor version
+    pub version: (u8, u8),
+}
+
+impl SubProduct {
+    /// code as used in identifiers
+    pub fn code(&self) -> &'static str {
+        match self {
+            SubProduct::S30 => "S30",
+            SubProduct::L30 => "L30",
+        }
+    }
+}
+
+impl fmt::Display for Product {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "HLS.{}.T{}.{}.v{}.{}",
+            self.sub_product.code(),
+            self.tile_number,
+            self.start_datetime.format("%Y%jT%H%M%S"),
+            self.version.0,
+            self.version.1
+        )
+    }
+}
+
+impl From<SubProduct> for Mission {
+    fn from(_: SubProduct) -> Self {
+        Mission::Hls
+    }
+}
+
+fn consume_product_sep(s: &str) -> IResult<&str, core::primitive::char> {
+    char('.')(s)
+}
+
+fn parse_sub_product(s: &str) -> IResult<&str, SubProduct> {
+    alt((
+        map(tag_no_case("s30"), |_| SubProduct::S30),
+        map(tag_no_case("l30"), |_| SubProduct::L30),
+    ))(s)
+}
+
+fn parse_tile_number(s: &str) -> IResult<&str, String> {
+    let (s, _) = tag_no_case("t")(s)?;
+    let (s, tn) = take_alphanumeric_n(5)(s)?;
+    Ok((s, tn.to_uppercase()))
+}
+
+fn parse_version(s: &str) -> IResult<&str, (u8, u8)> {
+    let (s, _) = tag_no_case("v")(s)?;
+    let (s, major) = take_n_digits(1)(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, minor) = take_n_digits(1)(s)?;
+    Ok((s, (major, minor)))
+}
+
+/// nom parser function
+pub fn parse_product(s: &str) -> IResult<&str, Product> {
+    let (s, _) = tag_no_case("hls")(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, sub_product) = parse_sub_product(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, tile_number) = parse_tile_number(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, date) = parse_julian_date(s)?;
+    let (s, _) = tag_no_case("t")(s)?;
+    let (s, time) = parse_simple_time(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, version) = parse_version(s)?;
+
+    Ok((
+        s,
+        Product {
+            sub_product,
+            tile_number,
+            start_datetime: NaiveDateTime::new(date, time),
+            version,
+        },
+    ))
+}
+
+impl_from_str!(parse_product, Product);
+
+#[cfg(test)]
+mod tests {
+    use crate::identifiers::hls::{parse_product, SubProduct};
+    use crate::identifiers::tests::apply_to_samples_from_txt;
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+    #[test]
+    fn parse_hls_product() {
+        let (_, product) = parse_product("HLS.S30.T53NMJ.2017005T013442.v2.0").unwrap();
+        assert_eq!(product.sub_product, SubProduct::S30);
+        assert_eq!(product.tile_number.as_str(), "53NMJ");
+        assert_eq!(
+            product.start_datetime,
+            NaiveDateTime::new(
+                NaiveDate::from_ymd_opt(2017, 1, 5).unwrap(),
+                NaiveTime::from_hms_opt(1, 34, 42).unwrap()
+            )
+        );
+        assert_eq!(product.version, (2, 0));
+    }
+
+    #[test]
+    fn product_display_roundtrip() {
+        let (_, product) = parse_product("hls.s30.t53nmj.2017005t013442.v2.0").unwrap();
+        assert_eq!(product.to_string(), "HLS.S30.T53NMJ.2017005T013442.v2.0");
+    }
+
+    #[test]
+    fn parse_hls_band_file() {
+        let (_, product) = parse_product("HLS.L30.T10SEG.2022154T184244.v2.0.B04.tif").unwrap();
+        assert_eq!(product.sub_product, SubProduct::L30);
+        assert_eq!(product.tile_number.as_str(), "10SEG");
+    }
+
+    #[test]
+    fn apply_to_product_testdata() {
+        apply_to_samples_from_txt("hls_products.txt", |s| {
+            parse_product(s).unwrap();
+        })
+    }
+}
@@ -16,17 +16,25 @@
 //! );
 //! ```
 use crate::common_parsers::{
-    date_year, parse_simple_date, take_alphanumeric, take_alphanumeric_n, take_n_digits,
-    take_n_digits_in_range,
+    is_char_alphanumeric, parse_julian_date, parse_simple_date, take_alphanumeric,
+    take_alphanumeric_n, take_n_digits, take_n_digits_in_range,
 };
-use crate::{impl_from_str, Mission, Name, NameLong};
-use chrono::{Duration, NaiveDate};
+use crate::from_str::impl_serde_via_str;
+use crate::{impl_from_str, Capabilities, JulianDate, Mission, Name, NameLong};
+use alloc::string::String;
+use chrono::NaiveDate;
+use core::fmt;
 use nom::branch::alt;
-use nom::bytes::complete::{tag, tag_no_case, take};
+use nom::bytes::complete::{tag, tag_no_case, take, take_while1};
+use nom::character::complete::anychar;
 use nom::combinator::{map, opt};
 use nom::error::ErrorKind;
 use nom::sequence::tuple;
 use nom::IResult;
+// float math of libm, unused when another crate in the build links std
+#[cfg(not(any(feature = "std", test)))]
+#[allow(unused_imports)]
+use num_traits::Float;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -61,6 +69,51 @@ impl From<u8> for MissionId {
     }
 }
 
+impl MissionId {
+    /// number of the satellite
+    pub fn number(&self) -> u8 {
+        match self {
+            MissionId::Landsat1 => 1,
+            MissionId::Landsat2 => 2,
+            MissionId::Landsat3 => 3,
+            MissionId::Landsat4 => 4,
+            MissionId::Landsat5 => 5,
+            MissionId::Landsat6 => 6,
+            MissionId::Landsat7 => 7,
+            MissionId::Landsat8 => 8,
+            MissionId::Landsat9 => 9,
+        }
+    }
+
+    /// launch and decommission date of the satellite
+    ///
+    /// The decommission date is `None` for satellites which are still operational.
+    pub fn operational_period(&self) -> (NaiveDate, Option<NaiveDate>) {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).expect("valid date");
+        match self {
+            MissionId::Landsat1 => (date(1972, 7, 23), Some(date(1978, 1, 6))),
+            MissionId::Landsat2 => (date(1975, 1, 22), Some(date(1982, 2, 25))),
+            MissionId::Landsat3 => (date(1978, 3, 5), Some(date(1983, 3, 31))),
+            MissionId::Landsat4 => (date(1982, 7, 16), Some(date(2001, 6, 15))),
+            MissionId::Landsat5 => (date(1984, 3, 1), Some(date(2013, 6, 5))),
+            // failed to reach orbit
+            MissionId::Landsat6 => (date(1993, 10, 5), Some(date(1993, 10, 5))),
+            MissionId::Landsat7 => (date(1999, 4, 15), Some(date(2025, 6, 4))),
+            MissionId::Landsat8 => (date(2013, 2, 11), None),
+            MissionId::Landsat9 => (date(2021, 9, 27), None),
+        }
+    }
+
+    /// check if the satellite was operational on the given date
+    ///
+    /// Acquisitions outside of this period are implausible, the parsers of scene ids and
+    /// products reject them.
+    pub fn is_operational_on(&self, date: NaiveDate) -> bool {
+        let (launch, decommission) = self.operational_period();
+        date >= launch && decommission.map(|d| date <= d).unwrap_or(true)
+    }
+}
+
 impl From<MissionId> for Mission {
     fn from(mission: MissionId) -> Self {
         match mission {
@@ -87,8 +140,9 @@ pub enum Sensor {
     /// O = OLI only
     OLI,
 
-    /// T = IRS only
-    IRS,
+    /// T = TIRS only
+    #[cfg_attr(feature = "serde", serde(alias = "IRS"))]
+    TIRS,
 
     /// E = ETM+
     ETM_PLUS,
@@ -99,13 +153,95 @@ pub enum Sensor {
     MSS,
 }
 
+impl Sensor {
+    /// former name of [`Sensor::TIRS`]
+    #[deprecated(note = "use Sensor::TIRS")]
+    pub const IRS: Sensor = Sensor::TIRS;
+
+    /// sensor from the single letter code used in identifiers
+    ///
+    /// The mission is required as `T` denotes TM on Landsat 4 & 5, but TIRS on
+    /// other missions.
+    pub fn from_code(c: char, mission: MissionId) -> Option<Sensor> {
+        match c.to_ascii_uppercase() {
+            'C' => Some(Sensor::OLI_TRIS),
+            'O' => Some(Sensor::OLI),
+            'T' => match mission {
+                MissionId::Landsat4 | MissionId::Landsat5 => Some(Sensor::TM),
+                _ => Some(Sensor::TIRS),
+            },
+            'E' => Some(Sensor::ETM_PLUS),
+            'M' => Some(Sensor::MSS),
+            _ => None,
+        }
+    }
+
+    /// check if the sensor was carried by the satellite of the mission
+    ///
+    /// | sensor                  | missions               |
+    /// |-------------------------|------------------------|
+    /// | OLI+TIRS, OLI, TIRS     | Landsat 8, 9           |
+    /// | ETM+                    | Landsat 7              |
+    /// | TM                      | Landsat 4, 5           |
+    /// | MSS                     | Landsat 1, 2, 3, 4, 5  |
+    pub fn is_carried_by(&self, mission: MissionId) -> bool {
+        match self {
+            Sensor::OLI_TRIS | Sensor::OLI | Sensor::TIRS => {
+                matches!(mission, MissionId::Landsat8 | MissionId::Landsat9)
+            }
+            Sensor::ETM_PLUS => mission == MissionId::Landsat7,
+            Sensor::TM => matches!(mission, MissionId::Landsat4 | MissionId::Landsat5),
+            Sensor::MSS => mission.number() <= 5,
+        }
+    }
+
+    /// single letter code used in identifiers
+    pub fn code(&self) -> char {
+        match self {
+            Sensor::OLI_TRIS => 'C',
+            Sensor::OLI => 'O',
+            Sensor::TIRS | Sensor::TM => 'T',
+            Sensor::ETM_PLUS => 'E',
+            Sensor::MSS => 'M',
+        }
+    }
+
+    /// nominal ground sample distance of the delivered multispectral bands in meters
+    ///
+    /// The thermal bands of TIRS (100m), ETM+ (60m) and TM (120m) are delivered resampled
+    /// to 30m. The 15m panchromatic bands of OLI and ETM+ are not considered.
+    pub fn gsd_meters(&self) -> u16 {
+        match self {
+            Sensor::OLI_TRIS | Sensor::OLI | Sensor::TIRS | Sensor::ETM_PLUS | Sensor::TM => 30,
+            Sensor::MSS => 60,
+        }
+    }
+
+    /// static facts about the sensor
+    pub fn capabilities(&self) -> Capabilities {
+        let (spectral_bands, has_thermal_bands) = match self {
+            Sensor::OLI_TRIS => (11, true),
+            Sensor::OLI => (9, false),
+            Sensor::TIRS => (2, true),
+            Sensor::ETM_PLUS => (8, true),
+            Sensor::TM => (7, true),
+            Sensor::MSS => (4, false),
+        };
+        Capabilities {
+            spectral_bands,
+            has_thermal_bands,
+            swath_width_km: 185,
+        }
+    }
+}
+
 impl Name for Sensor {
     fn name(&self) -> &str {
         // https://en.wikipedia.org/wiki/Landsat_program
         match self {
-            Sensor::OLI_TRIS => "OLI+TRIS",
+            Sensor::OLI_TRIS => "OLI+TIRS",
             Sensor::OLI => "OLI",
-            Sensor::IRS => "IRS",
+            Sensor::TIRS => "TIRS",
             Sensor::ETM_PLUS => "ETM+",
             Sensor::TM => "TM",
             Sensor::MSS => "MSS",
@@ -117,9 +253,9 @@ impl NameLong for Sensor {
     fn name_long(&self) -> &str {
         // https://en.wikipedia.org/wiki/Landsat_program
         match self {
-            Sensor::OLI_TRIS => "Operational Land Imager+TRIS",
+            Sensor::OLI_TRIS => "Operational Land Imager+Thermal Infrared Sensor",
             Sensor::OLI => "Operational Land Imager",
-            Sensor::IRS => "InfraRed Sensor",
+            Sensor::TIRS => "Thermal Infrared Sensor",
             Sensor::ETM_PLUS => "Enhanced Thematic Mapper Plus",
             Sensor::TM => "Thematic Mapper",
             Sensor::MSS => "Multi Spectral Scanner",
@@ -127,15 +263,6 @@ impl NameLong for Sensor {
     }
 }
 
-fn parse_julian_date(s: &str) -> IResult<&str, NaiveDate> {
-    let (s, year) = date_year(s)?;
-    let (s_out, day_of_year) = take_n_digits::<i64>(3)(s)?;
-    let date = NaiveDate::from_ymd_opt(year, 1, 1)
-        .ok_or_else(|| nom::Err::Error(nom::error::Error::new(s, ErrorKind::Fail)))?
-        + Duration::days(day_of_year - 1);
-    Ok((s_out, date))
-}
-
 /// Landsat scene id
 ///
 /// <https://gisgeography.com/landsat-file-naming-convention/>
@@ -159,21 +286,125 @@ pub struct SceneId {
     pub archive_version_number: u8,
 }
 
+impl SceneId {
+    /// approximate centre of the scene as `(longitude, latitude)`, see [`wrs2_centroid`]
+    pub fn wrs2_centroid(&self) -> Option<(f64, f64)> {
+        wrs2_centroid(self.mission, self.wrs_path, self.wrs_row)
+    }
+}
+
+/// number of paths of the WRS-2 grid
+const WRS2_PATHS: u32 = 233;
+
+/// number of rows of the WRS-2 grid
+const WRS2_ROWS: u32 = 248;
+
+/// row of the WRS-2 grid located at the descending node
+const WRS2_EQUATOR_ROW: u32 = 60;
+
+/// longitude of the descending node of path 1
+const WRS2_PATH1_NODE_LON: f64 = -64.6;
+
+/// orbit inclination of Landsat 4 and later, in degrees
+const WRS2_INCLINATION: f64 = 98.2;
+
+/// rotation of the earth during one orbit of 98.9 minutes, in degrees
+const WRS2_EARTH_ROTATION_PER_ORBIT: f64 = 98.9 * 360.0 / 1440.0;
+
+/// approximate centre of a WRS-2 scene as `(longitude, latitude)`
+///
+/// Derived from the path and row on a spherical earth: the path defines the longitude of
+/// the descending node - path 1 crosses the equator at 64.6° W and the paths follow
+/// westwards - and the row the position along the orbit, with row 60 at the equator. The
+/// result may be off by about a degree from the published scene centres, which is good
+/// enough for coarse spatial indexing but not for anything requiring the actual footprint.
+///
+/// Only Landsat 4 and later use the WRS-2 grid, `None` is returned for the WRS-1
+/// missions Landsat 1 to 3 and for paths or rows outside of the grid.
+pub fn wrs2_centroid(mission: MissionId, wrs_path: u32, wrs_row: u32) -> Option<(f64, f64)> {
+    if mission.number() < 4
+        || !(1..=WRS2_PATHS).contains(&wrs_path)
+        || !(1..=WRS2_ROWS).contains(&wrs_row)
+    {
+        return None;
+    }
+    let inclination = WRS2_INCLINATION.to_radians();
+
+    // angle along the orbit from the descending node, positive towards the north
+    let orbit_angle =
+        ((WRS2_EQUATOR_ROW as f64 - wrs_row as f64) * 360.0 / WRS2_ROWS as f64).to_radians();
+    let lat = (inclination.sin() * orbit_angle.sin()).asin().to_degrees();
+
+    // the orbit is retrograde, so the track north of the node is east of it. The earth
+    // rotates further east until the satellite reaches the node
+    let orbit_lon_offset = (-inclination.cos() * orbit_angle.sin())
+        .atan2(orbit_angle.cos())
+        .to_degrees();
+    let rotation_lon_offset = orbit_angle.to_degrees() / 360.0 * WRS2_EARTH_ROTATION_PER_ORBIT;
+    let node_lon = WRS2_PATH1_NODE_LON - (wrs_path - 1) as f64 * 360.0 / WRS2_PATHS as f64;
+    let lon = node_lon + orbit_lon_offset + rotation_lon_offset;
+    // wrap into -180° to 180°
+    let lon = lon - 360.0 * ((lon + 180.0) / 360.0).floor();
+
+    Some((lon, lat))
+}
+
+/// the acquisition date
+impl JulianDate for SceneId {
+    fn day_of_year(&self) -> u32 {
+        self.acquire_date.day_of_year()
+    }
+
+    fn julian_string(&self) -> String {
+        self.acquire_date.julian_string()
+    }
+}
+
+impl fmt::Display for SceneId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "L{}{}{:03}{:03}{}{}{:02}",
+            self.sensor.code(),
+            self.mission.number(),
+            self.wrs_path,
+            self.wrs_row,
+            self.acquire_date.format("%Y%j"),
+            self.ground_station_identifier,
+            self.archive_version_number
+        )
+    }
+}
+
+/// parse the sensor code, rejecting sensors not carried by the satellite of the mission
 fn parse_sensor(s: &str, mission: u8) -> IResult<&str, Sensor> {
-    alt((
-        map(tag_no_case("c"), |_| Sensor::OLI_TRIS),
-        map(tag_no_case("o"), |_| Sensor::OLI),
-        map(tag_no_case("t"), |_| {
-            // T = TM for Landsat 4 & 5)
-            if mission == 4 || mission == 5 {
-                Sensor::TM
-            } else {
-                Sensor::IRS
-            }
-        }),
-        map(tag_no_case("e"), |_| Sensor::ETM_PLUS),
-        map(tag_no_case("m"), |_| Sensor::MSS),
-    ))(s)
+    let (s_out, code) = anychar(s)?;
+    let sensor = Sensor::from_code(code, mission.into())
+        .ok_or_else(|| nom::Err::Error(nom::error::Error::new(s, ErrorKind::Tag)))?;
+    if !sensor.is_carried_by(mission.into()) {
+        return Err(nom::Err::Error(nom::error::Error::new(
+            s,
+            ErrorKind::Verify,
+        )));
+    }
+    Ok((s_out, sensor))
+}
+
+/// reject acquisitions outside of the operational period of the satellite, `s` is the input
+/// starting at the acquisition date
+fn verify_operational_on(
+    s: &str,
+    mission: MissionId,
+    acquire_date: NaiveDate,
+) -> Result<(), nom::Err<nom::error::Error<&str>>> {
+    if mission.is_operational_on(acquire_date) {
+        Ok(())
+    } else {
+        Err(nom::Err::Error(nom::error::Error::new(
+            s,
+            ErrorKind::Verify,
+        )))
+    }
 }
 
 /// nom parser function
@@ -184,7 +415,9 @@ pub fn parse_scene_id(s: &str) -> IResult<&str, SceneId> {
     let (_, sensor) = parse_sensor(s_sensor, mission)?;
     let (s, wrs_path) = take_n_digits(3)(s)?;
     let (s, wrs_row) = take_n_digits(3)(s)?;
+    let s_date = s;
     let (s, acquire_date) = parse_julian_date(s)?;
+    verify_operational_on(s_date, mission.into(), acquire_date)?;
     let (s, ground_station_identifier) = take_alphanumeric_n(3)(s)?;
     let (s, archive_version_number) = take_n_digits(2)(s)?;
     Ok((
@@ -220,8 +453,25 @@ pub enum ProcessingLevel {
     Other(String),
 }
 
+impl ProcessingLevel {
+    /// code as used in identifiers
+    pub fn code(&self) -> &str {
+        match self {
+            ProcessingLevel::L1TP => "L1TP",
+            ProcessingLevel::L1GT => "L1GT",
+            ProcessingLevel::L1GS => "L1GS",
+            ProcessingLevel::L2SP => "L2SP",
+            ProcessingLevel::L2SR => "L2SR",
+            ProcessingLevel::CU => "CU",
+            ProcessingLevel::AK => "AK",
+            ProcessingLevel::HI => "HI",
+            ProcessingLevel::Other(s) => s.as_str(),
+        }
+    }
+}
+
+/// serialized as the two letter code used in the product name
 #[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Hash, Copy)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CollectionCategory {
     RealTime,
     Tier1,
@@ -230,6 +480,19 @@ pub enum CollectionCategory {
     AlbersTier2,
 }
 
+impl CollectionCategory {
+    /// rank of the category by data quality, higher is better
+    ///
+    /// Tier 1 ranks above Tier 2, which ranks above Real-Time.
+    pub fn quality_rank(&self) -> u8 {
+        match self {
+            CollectionCategory::RealTime => 1,
+            CollectionCategory::Tier2 | CollectionCategory::AlbersTier2 => 2,
+            CollectionCategory::Tier1 | CollectionCategory::AlbersTier1 => 3,
+        }
+    }
+}
+
 impl Name for CollectionCategory {
     fn name(&self) -> &str {
         match self {
@@ -254,6 +517,49 @@ impl NameLong for CollectionCategory {
     }
 }
 
+impl fmt::Display for CollectionCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl_serde_via_str!(CollectionCategory);
+
+/// Landsat collection the product was processed in
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Collection {
+    /// Collection 1
+    C1,
+
+    /// Collection 2
+    C2,
+
+    /// collection number not known to this crate
+    Unknown(u8),
+}
+
+impl From<u8> for Collection {
+    fn from(v: u8) -> Self {
+        match v {
+            1 => Self::C1,
+            2 => Self::C2,
+            _ => Self::Unknown(v),
+        }
+    }
+}
+
+impl Collection {
+    /// collection number as used in the product name
+    pub fn number(&self) -> u8 {
+        match self {
+            Collection::C1 => 1,
+            Collection::C2 => 2,
+            Collection::Unknown(v) => *v,
+        }
+    }
+}
+
 /// Landsat product
 ///
 /// <https://gisgeography.com/landsat-file-naming-convention/>
@@ -279,6 +585,64 @@ pub struct Product {
     pub collection_category: Option<CollectionCategory>,
 }
 
+impl Product {
+    /// check if the product was acquired by Landsat 7 after the failure of the
+    /// Scan Line Corrector (SLC) on 2003-05-31
+    ///
+    /// SLC-off products contain data gaps in a striping pattern.
+    pub fn is_slc_off(&self) -> bool {
+        self.mission == MissionId::Landsat7
+            && self.acquire_date > NaiveDate::from_ymd_opt(2003, 5, 31).expect("valid date")
+    }
+
+    /// approximate centre of the scene as `(longitude, latitude)`, see [`wrs2_centroid`]
+    pub fn wrs2_centroid(&self) -> Option<(f64, f64)> {
+        wrs2_centroid(self.mission, self.wrs_path, self.wrs_row)
+    }
+
+    /// the collection of the product, derived from the `collection_number`
+    pub fn collection(&self) -> Collection {
+        Collection::from(self.collection_number)
+    }
+
+    /// check if the product belongs to Collection 2
+    pub fn is_collection2(&self) -> bool {
+        self.collection() == Collection::C2
+    }
+}
+
+/// the acquisition date
+impl JulianDate for Product {
+    fn day_of_year(&self) -> u32 {
+        self.acquire_date.day_of_year()
+    }
+
+    fn julian_string(&self) -> String {
+        self.acquire_date.julian_string()
+    }
+}
+
+impl fmt::Display for Product {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "L{}{:02}_{}_{:03}{:03}_{}_{}_{:02}",
+            self.sensor.code(),
+            self.mission.number(),
+            self.processing_level.code(),
+            self.wrs_path,
+            self.wrs_row,
+            self.acquire_date.format("%Y%m%d"),
+            self.processing_date.format("%Y%m%d"),
+            self.collection_number
+        )?;
+        if let Some(collection_category) = self.collection_category {
+            write!(f, "_{}", collection_category)?;
+        }
+        Ok(())
+    }
+}
+
 fn consume_product_sep(s: &str) -> IResult<&str, &str> {
     tag("_")(s)
 }
@@ -322,7 +686,9 @@ pub fn parse_product(s: &str) -> IResult<&str, Product> {
     let (s, wrs_path) = take_n_digits(3)(s)?;
     let (s, wrs_row) = take_n_digits(3)(s)?;
     let (s, _) = consume_product_sep(s)?;
+    let s_date = s;
     let (s, acquire_date) = parse_simple_date(s)?;
+    verify_operational_on(s_date, mission.into(), acquire_date)?;
     let (s, _) = consume_product_sep(s)?;
     let (s, processing_date) = parse_simple_date(s)?;
     let (s, _) = consume_product_sep(s)?;
@@ -347,22 +713,198 @@ pub fn parse_product(s: &str) -> IResult<&str, Product> {
     ))
 }
 
+/// select the best product from products of the same scene
+///
+/// The product with the highest ranked collection category is preferred, ties are
+/// resolved by choosing the most recently processed product. Products without a
+/// collection category rank lowest.
+pub fn best_of(products: &[Product]) -> Option<&Product> {
+    products.iter().max_by_key(|p| {
+        (
+            p.collection_category
+                .map(|cc| cc.quality_rank())
+                .unwrap_or(0),
+            p.processing_date,
+        )
+    })
+}
+
+/// metadata sidecar file of a product
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MetadataAsset {
+    /// level-1/level-2 metadata file
+    MTL,
+
+    /// angle coefficient file
+    ANG,
+}
+
+impl Name for MetadataAsset {
+    fn name(&self) -> &str {
+        match self {
+            MetadataAsset::MTL => "MTL",
+            MetadataAsset::ANG => "ANG",
+        }
+    }
+}
+
+impl NameLong for MetadataAsset {
+    fn name_long(&self) -> &str {
+        match self {
+            MetadataAsset::MTL => "Metadata",
+            MetadataAsset::ANG => "Angle Coefficients",
+        }
+    }
+}
+
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AssetKind {
+    /// band or quality layer, for example `SR_B4` or `QA_PIXEL`
+    Band(String),
+
+    /// metadata sidecar
+    MetadataAsset(MetadataAsset),
+}
+
+/// file of a Landsat product, for example `LC08_L2SP_140041_20130503_20190828_02_T1_SR_B4.TIF`
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Asset {
+    pub product: Product,
+    pub kind: AssetKind,
+
+    /// file extension, without the leading dot
+    pub extension: String,
+}
+
+fn is_char_asset_name(chr: char) -> bool {
+    chr == '_' || is_char_alphanumeric(chr)
+}
+
+fn parse_asset_kind(s: &str) -> IResult<&str, AssetKind> {
+    let (s_out, name) = take_while1(is_char_asset_name)(s)?;
+    let kind = if name.eq_ignore_ascii_case("mtl") {
+        AssetKind::MetadataAsset(MetadataAsset::MTL)
+    } else if name.eq_ignore_ascii_case("ang") {
+        AssetKind::MetadataAsset(MetadataAsset::ANG)
+    } else {
+        AssetKind::Band(name.to_uppercase())
+    };
+    Ok((s_out, kind))
+}
+
+/// nom parser function
+pub fn parse_asset(s: &str) -> IResult<&str, Asset> {
+    let (s, product) = parse_product(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, kind) = parse_asset_kind(s)?;
+    let (s, _) = tag(".")(s)?;
+    let (s, extension) = take_while1(is_char_alphanumeric)(s)?;
+    Ok((
+        s,
+        Asset {
+            product,
+            kind,
+            extension: extension.to_lowercase(),
+        },
+    ))
+}
+
 impl_from_str!(parse_product, Product);
 impl_from_str!(parse_scene_id, SceneId);
+impl_from_str!(parse_collection_category, CollectionCategory);
+impl_from_str!(parse_asset, Asset);
 
 #[cfg(test)]
 mod tests {
     use crate::identifiers::landsat::{
-        parse_julian_date, parse_product, parse_scene_id, CollectionCategory, MissionId,
-        ProcessingLevel, Sensor,
+        best_of, parse_asset, parse_product, parse_scene_id, wrs2_centroid, AssetKind, Collection,
+        CollectionCategory, MetadataAsset, MissionId, ProcessingLevel, Product, Sensor,
     };
     use crate::identifiers::tests::apply_to_samples_from_txt;
+    use crate::{JulianDate, Name, NameLong};
     use chrono::NaiveDate;
+    use nom::error::ErrorKind;
+    use std::str::FromStr;
+
+    #[test]
+    fn wrs2_centroids() {
+        let assert_near = |centroid: Option<(f64, f64)>, expected: (f64, f64)| {
+            let (lon, lat) = centroid.unwrap();
+            assert!(
+                (lon - expected.0).abs() < 1.0 && (lat - expected.1).abs() < 1.0,
+                "{:?} != {:?}",
+                (lon, lat),
+                expected
+            );
+        };
+
+        // descending node of path 1
+        assert_near(wrs2_centroid(MissionId::Landsat8, 1, 60), (-64.6, 0.0));
+        // Washington DC
+        assert_near(wrs2_centroid(MissionId::Landsat8, 15, 33), (-77.0, 38.9));
+        // San Francisco bay area
+        let (_, product) = parse_product("LC08_L1TP_044034_20210508_20210518_02_T1").unwrap();
+        assert_near(product.wrs2_centroid(), (-122.2, 37.3));
+
+        let (_, scene) = parse_scene_id("LC80390222013076EDC00").unwrap();
+        assert!(scene.wrs2_centroid().is_some());
+
+        // WRS-1 and out of range
+        assert_eq!(wrs2_centroid(MissionId::Landsat1, 15, 33), None);
+        assert_eq!(wrs2_centroid(MissionId::Landsat8, 234, 33), None);
+        assert_eq!(wrs2_centroid(MissionId::Landsat8, 15, 0), None);
+    }
 
     #[test]
-    fn test_parse_julian_date() {
-        let (_, d) = parse_julian_date("2020046").unwrap();
-        assert_eq!(d, NaiveDate::from_ymd_opt(2020, 2, 15).unwrap());
+    fn julian_date() {
+        let (_, scene) = parse_scene_id("LC80390222013076EDC00").unwrap();
+        assert_eq!(scene.day_of_year(), 76);
+        assert_eq!(scene.julian_string(), "2013076");
+
+        let (_, product) = parse_product("LC08_L2SP_140041_20130503_20190828_02_T1").unwrap();
+        assert_eq!(product.day_of_year(), 123);
+        assert_eq!(product.julian_string(), "2013123");
+    }
+
+    #[test]
+    fn product_collection() {
+        let (_, product) = parse_product("LC08_L1TP_044034_20170105_20170218_01_T1").unwrap();
+        assert_eq!(product.collection(), Collection::C1);
+        assert!(!product.is_collection2());
+
+        let (_, product) = parse_product("LC08_L2SP_140041_20130503_20190828_02_T1").unwrap();
+        assert_eq!(product.collection(), Collection::C2);
+        assert!(product.is_collection2());
+
+        let (_, product) = parse_product("LC08_L2SP_140041_20130503_20190828_03_T1").unwrap();
+        assert_eq!(product.collection(), Collection::Unknown(3));
+        assert_eq!(product.collection().number(), 3);
+        assert!(!product.is_collection2());
+    }
+
+    #[test]
+    fn parse_lowercase_product() {
+        let (rest, product) = parse_product("lc08_l2sp_140041_20130503_20190828_02_t1").unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(product.collection_category, Some(CollectionCategory::Tier1));
+        assert_eq!(
+            product.to_string(),
+            "LC08_L2SP_140041_20130503_20190828_02_T1"
+        );
+        assert_eq!(Product::from_str(&product.to_string()).unwrap(), product);
+
+        let (_, product) = parse_product("le07_l1gt_044034_20210508_20210603_02_rt").unwrap();
+        assert_eq!(
+            product.collection_category,
+            Some(CollectionCategory::RealTime)
+        );
+        assert_eq!(
+            product.to_string(),
+            "LE07_L1GT_044034_20210508_20210603_02_RT"
+        );
     }
 
     #[test]
@@ -380,6 +922,142 @@ mod tests {
         assert_eq!(scene.archive_version_number, 0);
     }
 
+    #[test]
+    fn scene_id_display_roundtrip() {
+        for s in [
+            "LC80390222013076EDC00",
+            "LE71810402006015ASN00",
+            "LT50440342011001PAC01",
+        ] {
+            let (_, scene) = parse_scene_id(s).unwrap();
+            assert_eq!(scene.to_string(), s);
+        }
+    }
+
+    #[test]
+    fn sensor_code_tm_irs_disambiguation() {
+        assert_eq!(
+            Sensor::from_code('T', MissionId::Landsat4),
+            Some(Sensor::TM)
+        );
+        assert_eq!(
+            Sensor::from_code('t', MissionId::Landsat5),
+            Some(Sensor::TM)
+        );
+        assert_eq!(
+            Sensor::from_code('T', MissionId::Landsat8),
+            Some(Sensor::TIRS)
+        );
+        assert_eq!(
+            Sensor::from_code('T', MissionId::Landsat9),
+            Some(Sensor::TIRS)
+        );
+        assert_eq!(Sensor::from_code('X', MissionId::Landsat8), None);
+
+        for (sensor, mission) in [
+            (Sensor::OLI_TRIS, MissionId::Landsat8),
+            (Sensor::OLI, MissionId::Landsat8),
+            (Sensor::TIRS, MissionId::Landsat8),
+            (Sensor::ETM_PLUS, MissionId::Landsat7),
+            (Sensor::TM, MissionId::Landsat5),
+            (Sensor::MSS, MissionId::Landsat1),
+        ] {
+            assert_eq!(Sensor::from_code(sensor.code(), mission), Some(sensor));
+        }
+    }
+
+    #[test]
+    fn sensor_mission_compatibility() {
+        for s in [
+            "LC08_L1TP_044034_20210508_20210518_02_T1",
+            "LO09_L1TP_044034_20220508_20220518_02_T1",
+            "LE07_L1TP_044034_20210508_20210603_02_T1",
+            "LT05_L1TP_044034_20110101_20200823_02_T1",
+            "LT04_L1TP_044034_19880101_20200917_02_T1",
+            "LM05_L1GS_044034_19880101_20200917_02_T2",
+            "LM01_L1GS_044034_19750101_20200917_02_T2",
+        ] {
+            assert!(parse_product(s).is_ok(), "{}", s);
+        }
+
+        for s in [
+            // OLI only on Landsat 8 and 9
+            "LO05_L1TP_044034_20110101_20200823_02_T1",
+            // TIRS is only carried by Landsat 8 and 9, T denotes TIRS on other missions
+            "LT07_L1TP_044034_20210508_20210603_02_T1",
+            "LT01_L1GS_044034_19750101_20200917_02_T2",
+            // ETM+ only on Landsat 7
+            "LE08_L1TP_044034_20210508_20210518_02_T1",
+            // MSS only on Landsat 1 to 5
+            "LM08_L1TP_044034_20210508_20210518_02_T1",
+            "LC07_L1TP_044034_20210508_20210603_02_T1",
+        ] {
+            assert!(parse_product(s).is_err(), "{}", s);
+        }
+        assert!(parse_scene_id("LE80390222013076EDC00").is_err());
+
+        apply_to_samples_from_txt("landsat_products.txt", |s| {
+            let (_, product) = parse_product(s).unwrap();
+            assert!(product.sensor.is_carried_by(product.mission));
+        });
+    }
+
+    #[test]
+    fn landsat8_thermal_sensor() {
+        let (_, scene) = parse_scene_id("LT80390222013076EDC00").unwrap();
+        assert_eq!(scene.sensor, Sensor::TIRS);
+        assert_eq!(scene.sensor.name(), "TIRS");
+        assert_eq!(scene.sensor.name_long(), "Thermal Infrared Sensor");
+        assert_eq!(Sensor::OLI_TRIS.name(), "OLI+TIRS");
+
+        #[allow(deprecated)]
+        let former = Sensor::IRS;
+        assert_eq!(former, Sensor::TIRS);
+    }
+
+    #[test]
+    fn sensor_gsd_meters() {
+        assert_eq!(Sensor::OLI_TRIS.gsd_meters(), 30);
+        assert_eq!(Sensor::OLI.gsd_meters(), 30);
+        assert_eq!(Sensor::TIRS.gsd_meters(), 30);
+        assert_eq!(Sensor::ETM_PLUS.gsd_meters(), 30);
+        assert_eq!(Sensor::TM.gsd_meters(), 30);
+        assert_eq!(Sensor::MSS.gsd_meters(), 60);
+    }
+
+    #[test]
+    fn mission_operational_period() {
+        let (_, product) = parse_product("LT05_L1TP_039022_20110612_20200822_02_T1").unwrap();
+        assert!(product.mission.is_operational_on(product.acquire_date));
+
+        // Landsat 5 was decommissioned in 2013
+        let s = "LT05_L1TP_039022_20140612_20200822_02_T1";
+        let err = parse_product(s).unwrap_err();
+        assert_eq!(
+            err,
+            nom::Err::Error(nom::error::Error::new(&s[17..], ErrorKind::Verify))
+        );
+        assert!(parse_scene_id("LT50390222014163EDC00").is_err());
+        assert!(parse_scene_id("LT50390222011163EDC00").is_ok());
+
+        assert!(MissionId::Landsat9.is_operational_on(NaiveDate::from_ymd_opt(2030, 1, 1).unwrap()));
+        assert!(
+            !MissionId::Landsat9.is_operational_on(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+        );
+    }
+
+    #[test]
+    fn product_is_slc_off() {
+        let (_, product) = parse_product("LE07_L1TP_039022_20030412_20200916_02_T1").unwrap();
+        assert!(!product.is_slc_off());
+
+        let (_, product) = parse_product("LE07_L1TP_039022_20030614_20200915_02_T1").unwrap();
+        assert!(product.is_slc_off());
+
+        let (_, product) = parse_product("LC08_L2SP_140041_20130503_20190828_02_T1").unwrap();
+        assert!(!product.is_slc_off());
+    }
+
     #[test]
     fn test_parse_product_l1() {
         let (_, product) = parse_product("LC08_L1GT_029030_20151209_20160131_01_RT").unwrap();
@@ -401,10 +1079,113 @@ mod tests {
         assert_eq!(product.collection_category, Some(CollectionCategory::Tier1));
     }
 
+    #[test]
+    fn collection_category_code_roundtrip() {
+        for cc in [
+            CollectionCategory::RealTime,
+            CollectionCategory::Tier1,
+            CollectionCategory::Tier2,
+            CollectionCategory::AlbersTier1,
+            CollectionCategory::AlbersTier2,
+        ] {
+            assert_eq!(CollectionCategory::from_str(&cc.to_string()).unwrap(), cc);
+        }
+        assert_eq!(CollectionCategory::Tier1.to_string(), "T1");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn collection_category_serde_uses_codes() {
+        assert_eq!(
+            serde_json::to_string(&CollectionCategory::Tier1).unwrap(),
+            r#""T1""#
+        );
+        assert_eq!(
+            serde_json::from_str::<CollectionCategory>(r#""RT""#).unwrap(),
+            CollectionCategory::RealTime
+        );
+        assert!(serde_json::from_str::<CollectionCategory>(r#""XX""#).is_err());
+    }
+
+    #[test]
+    fn best_of_prefers_tier1() {
+        let products = [
+            "LC08_L1GT_029030_20151209_20151210_01_RT",
+            "LC08_L1TP_029030_20151209_20160131_01_T1",
+            "LC08_L1GT_029030_20151209_20160201_01_T2",
+        ]
+        .map(|s| parse_product(s).unwrap().1);
+        let best = best_of(&products).unwrap();
+        assert_eq!(best.collection_category, Some(CollectionCategory::Tier1));
+        assert!(best_of(&[]).is_none());
+    }
+
+    #[test]
+    fn test_parse_asset_metadata() {
+        for (s, metadata_asset, extension) in [
+            (
+                "LC08_L2SP_140041_20130503_20190828_02_T1_MTL.txt",
+                MetadataAsset::MTL,
+                "txt",
+            ),
+            (
+                "LC08_L2SP_140041_20130503_20190828_02_T1_MTL.json",
+                MetadataAsset::MTL,
+                "json",
+            ),
+            (
+                "LC08_L2SP_140041_20130503_20190828_02_T1_ANG.txt",
+                MetadataAsset::ANG,
+                "txt",
+            ),
+            (
+                "lc08_l2sp_140041_20130503_20190828_02_t1_ang.txt",
+                MetadataAsset::ANG,
+                "txt",
+            ),
+        ] {
+            let (_, asset) = parse_asset(s).unwrap();
+            assert_eq!(asset.kind, AssetKind::MetadataAsset(metadata_asset));
+            assert_eq!(asset.extension, extension);
+            assert_eq!(asset.product.wrs_path, 140);
+            assert_eq!(
+                asset.product.collection_category,
+                Some(CollectionCategory::Tier1)
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_asset_band() {
+        let (_, asset) = parse_asset("LC08_L2SP_140041_20130503_20190828_02_T1_SR_B4.TIF").unwrap();
+        assert_eq!(asset.kind, AssetKind::Band("SR_B4".to_string()));
+        assert_eq!(asset.extension, "tif");
+
+        let (_, asset) =
+            parse_asset("LC08_L2SP_140041_20130503_20190828_02_T1_QA_PIXEL.TIF").unwrap();
+        assert_eq!(asset.kind, AssetKind::Band("QA_PIXEL".to_string()));
+
+        assert!(parse_asset("LC08_L2SP_140041_20130503_20190828_02_T1").is_err());
+    }
+
     #[test]
     fn apply_to_product_testdata() {
         apply_to_samples_from_txt("landsat_products.txt", |s| {
             parse_product(s).unwrap();
         })
     }
+
+    #[test]
+    fn product_display_roundtrip() {
+        apply_to_samples_from_txt("landsat_products.txt", |s| {
+            let (_, product) = parse_product(s).unwrap();
+            assert_eq!(product.to_string(), s);
+        });
+
+        let (_, product) = parse_product("lc08_l1gt_029030_20151209_20160131_01_rt").unwrap();
+        assert_eq!(
+            product.to_string(),
+            "LC08_L1GT_029030_20151209_20160131_01_RT"
+        );
+    }
 }
@@ -1,3 +1,4 @@
+pub mod hls;
 pub mod landsat;
 pub mod modis;
 pub mod planet;
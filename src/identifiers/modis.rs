@@ -1,5 +1,321 @@
-// https://lpdaac.usgs.gov/data/get-started-data/collection-overview/missions/modis-overview/#modis-naming-conventions
+//! MODIS
+//!
+//! [naming convention](https://lpdaac.usgs.gov/data/get-started-data/collection-overview/missions/modis-overview/#modis-naming-conventions)
+//!
+//! # Example
+//!
+//! ```rust
+//! use eo_identifiers::identifiers::modis::Product;
+//! use std::str::FromStr;
+//!
+//! assert!(
+//!     Product::from_str("MOD09GA.A2023001.h18v04.061.2023003011222.hdf")
+//!     .is_ok()
+//! );
+//! ```
 // https://web.archive.org/web/20220605230522/https://lpdaac.usgs.gov/data/get-started-data/collection-overview/missions/modis-overview/
 // https://modis.gsfc.nasa.gov/data/dataprod/
+use crate::common_parsers::{
+    parse_julian_date, parse_simple_time, take_alphanumeric, take_n_digits, take_n_digits_in_range,
+};
+use crate::{impl_from_str, Capabilities, Mission, Name, NameLong};
+use alloc::format;
+use alloc::string::String;
+use chrono::{NaiveDate, NaiveDateTime};
+use core::fmt;
+use nom::branch::alt;
+use nom::bytes::complete::tag_no_case;
+use nom::character::complete::char;
+use nom::combinator::map;
+use nom::IResult;
+// float math of libm, unused when another crate in the build links std
+#[cfg(not(any(feature = "std", test)))]
+#[allow(unused_imports)]
+use num_traits::Float;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-//! TODO
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Platform {
+    /// MOD
+    Terra,
+
+    /// MYD
+    Aqua,
+
+    /// MCD = combined Terra and Aqua
+    Combined,
+}
+
+impl Name for Platform {
+    fn name(&self) -> &str {
+        match self {
+            Platform::Terra => "Terra",
+            Platform::Aqua => "Aqua",
+            Platform::Combined => "Terra+Aqua",
+        }
+    }
+}
+
+impl NameLong for Platform {
+    fn name_long(&self) -> &str {
+        match self {
+            Platform::Terra => "Terra",
+            Platform::Aqua => "Aqua",
+            Platform::Combined => "Combined Terra and Aqua",
+        }
+    }
+}
+
+impl From<Platform> for Mission {
+    fn from(_: Platform) -> Self {
+        Mission::Modis
+    }
+}
+
+/// static facts about the MODIS instrument
+pub const MODIS_CAPABILITIES: Capabilities = Capabilities {
+    spectral_bands: 36,
+    has_thermal_bands: true,
+    swath_width_km: 2330,
+};
+
+/// MODIS product granule
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Product {
+    /// short name of the product, for example `MOD09GA`
+    pub product_short_name: String,
+
+    /// satellite, derived from the prefix of the short name
+    pub platform: Platform,
+
+    /// acquisition date
+    pub acquire_date: NaiveDate,
+
+    /// horizontal tile number of the sinusoidal grid (0 - 35)
+    pub horizontal_tile: u8,
+
+    /// vertical tile number of the sinusoidal grid (0 - 17)
+    pub vertical_tile: u8,
+
+    /// collection version
+    pub collection: u16,
+
+    /// production datetime
+    pub production_datetime: NaiveDateTime,
+}
+
+/// edge length of a tile of the sinusoidal grid, in degrees
+const TILE_SIZE_DEGREES: f64 = 10.0;
+
+/// number of tiles of the sinusoidal grid in horizontal direction
+const HORIZONTAL_TILES: u8 = 36;
+
+/// number of tiles of the sinusoidal grid in vertical direction
+const VERTICAL_TILES: u8 = 18;
+
+impl Product {
+    /// geographic bounds of the tile as `(min_lon, min_lat, max_lon, max_lat)`
+    ///
+    /// The tile extent in the sinusoidal projection is derived from `horizontal_tile` and
+    /// `vertical_tile` using the 10 degree tile spacing of the grid, with the origin of the
+    /// grid at (-180, 90). The corners of this extent are reprojected to WGS84 and the bounds
+    /// of these corners are returned. Longitudes are clamped to the -180 to 180 range, so
+    /// tiles at the edges of the grid may be partially outside of the valid area.
+    ///
+    /// `None` for tile numbers outside of the grid.
+    pub fn tile_bounds(&self) -> Option<(f64, f64, f64, f64)> {
+        if self.horizontal_tile >= HORIZONTAL_TILES || self.vertical_tile >= VERTICAL_TILES {
+            return None;
+        }
+        let max_lat = 90.0 - TILE_SIZE_DEGREES * self.vertical_tile as f64;
+        let min_lat = max_lat - TILE_SIZE_DEGREES;
+        let min_x = -180.0 + TILE_SIZE_DEGREES * self.horizontal_tile as f64;
+        let max_x = min_x + TILE_SIZE_DEGREES;
+
+        let (min_lon, max_lon) = [min_lat, max_lat]
+            .iter()
+            .flat_map(|lat| {
+                let cos_lat = lat.to_radians().cos();
+                [min_x / cos_lat, max_x / cos_lat]
+            })
+            .map(|lon| lon.clamp(-180.0, 180.0))
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), lon| {
+                (min.min(lon), max.max(lon))
+            });
+        Some((min_lon, min_lat, max_lon, max_lat))
+    }
+}
+
+impl fmt::Display for Product {
+    /// the granule name, without the file extension
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.A{}.h{:02}v{:02}.{:03}.{}",
+            self.product_short_name,
+            self.acquire_date.format("%Y%j"),
+            self.horizontal_tile,
+            self.vertical_tile,
+            self.collection,
+            self.production_datetime.format("%Y%j%H%M%S")
+        )
+    }
+}
+
+fn consume_product_sep(s: &str) -> IResult<&str, core::primitive::char> {
+    char('.')(s)
+}
+
+fn parse_platform(s: &str) -> IResult<&str, Platform> {
+    alt((
+        map(tag_no_case("mod"), |_| Platform::Terra),
+        map(tag_no_case("myd"), |_| Platform::Aqua),
+        map(tag_no_case("mcd"), |_| Platform::Combined),
+    ))(s)
+}
+
+/// nom parser function
+pub fn parse_product(s: &str) -> IResult<&str, Product> {
+    let s_short_name = s;
+    let (s, platform) = parse_platform(s)?;
+    let (s, short_name_suffix) = take_alphanumeric(s)?;
+    let product_short_name = format!("{}{}", &s_short_name[..3], short_name_suffix).to_uppercase();
+    let (s, _) = consume_product_sep(s)?;
+    let (s, _) = tag_no_case("a")(s)?;
+    let (s, acquire_date) = parse_julian_date(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, _) = tag_no_case("h")(s)?;
+    let (s, horizontal_tile) = take_n_digits_in_range(2, 0..HORIZONTAL_TILES)(s)?;
+    let (s, _) = tag_no_case("v")(s)?;
+    let (s, vertical_tile) = take_n_digits_in_range(2, 0..VERTICAL_TILES)(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, collection) = take_n_digits(3)(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, production_date) = parse_julian_date(s)?;
+    let (s, production_time) = parse_simple_time(s)?;
+
+    Ok((
+        s,
+        Product {
+            product_short_name,
+            platform,
+            acquire_date,
+            horizontal_tile,
+            vertical_tile,
+            collection,
+            production_datetime: NaiveDateTime::new(production_date, production_time),
+        },
+    ))
+}
+
+impl_from_str!(parse_product, Product);
+
+#[cfg(test)]
+mod tests {
+    use crate::identifiers::modis::{parse_product, Platform};
+    use crate::identifiers::tests::apply_to_samples_from_txt;
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+    #[test]
+    fn parse_modis_product() {
+        let (_, product) = parse_product("MOD09GA.A2023001.h18v04.061.2023003011222.hdf").unwrap();
+        assert_eq!(product.product_short_name.as_str(), "MOD09GA");
+        assert_eq!(product.platform, Platform::Terra);
+        assert_eq!(
+            product.acquire_date,
+            NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()
+        );
+        assert_eq!(product.horizontal_tile, 18);
+        assert_eq!(product.vertical_tile, 4);
+        assert_eq!(product.collection, 61);
+        assert_eq!(
+            product.production_datetime,
+            NaiveDateTime::new(
+                NaiveDate::from_ymd_opt(2023, 1, 3).unwrap(),
+                NaiveTime::from_hms_opt(1, 12, 22).unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn parse_modis_platforms() {
+        let (_, product) = parse_product("myd09ga.a2023001.h18v04.061.2023003034510.hdf").unwrap();
+        assert_eq!(product.platform, Platform::Aqua);
+        assert_eq!(product.product_short_name.as_str(), "MYD09GA");
+
+        let (_, product) = parse_product("MCD43A4.A2022365.h10v05.061.2023009030147.hdf").unwrap();
+        assert_eq!(product.platform, Platform::Combined);
+
+        assert!(parse_product("MOD09GA.A2023001.h36v04.061.2023003011222.hdf").is_err());
+        assert!(parse_product("MOD09GA.A2023001.h18v18.061.2023003011222.hdf").is_err());
+    }
+
+    fn assert_bounds(name: &str, expected: (f64, f64, f64, f64)) {
+        let (_, product) = parse_product(name).unwrap();
+        let bounds = product.tile_bounds().unwrap();
+        for (value, expected_value) in [
+            (bounds.0, expected.0),
+            (bounds.1, expected.1),
+            (bounds.2, expected.2),
+            (bounds.3, expected.3),
+        ] {
+            assert!(
+                (value - expected_value).abs() < 0.01,
+                "{:?} != {:?}",
+                bounds,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn tile_bounds() {
+        assert_bounds(
+            "MOD09GA.A2023001.h18v04.061.2023003011222.hdf",
+            (0.0, 40.0, 15.557, 50.0),
+        );
+        assert_bounds(
+            "MOD09GA.A2023001.h08v05.061.2023003011222.hdf",
+            (-130.541, 30.0, -103.923, 40.0),
+        );
+        assert_bounds(
+            "MOD09GA.A2023001.h12v12.061.2023003011222.hdf",
+            (-78.324, -40.0, -57.735, -30.0),
+        );
+        // clamped at the antimeridian
+        assert_bounds(
+            "MOD09GA.A2023001.h00v08.061.2023003011222.hdf",
+            (-180.0, 0.0, -170.0, 10.0),
+        );
+    }
+
+    #[test]
+    fn tile_bounds_outside_of_grid() {
+        let (_, mut product) =
+            parse_product("MOD09GA.A2023001.h18v04.061.2023003011222.hdf").unwrap();
+        product.horizontal_tile = 36;
+        assert_eq!(product.tile_bounds(), None);
+
+        product.horizontal_tile = 18;
+        product.vertical_tile = 18;
+        assert_eq!(product.tile_bounds(), None);
+    }
+
+    #[test]
+    fn product_display_roundtrip() {
+        apply_to_samples_from_txt("modis_products.txt", |s| {
+            let (_, product) = parse_product(s).unwrap();
+            assert_eq!(product.to_string(), s.trim_end_matches(".hdf"));
+        })
+    }
+
+    #[test]
+    fn apply_to_product_testdata() {
+        apply_to_samples_from_txt("modis_products.txt", |s| {
+            parse_product(s).unwrap();
+        })
+    }
+}
@@ -1,4 +1,188 @@
+//! PlanetScope
+//!
+//! [naming convention](https://developers.planet.com/docs/data/planetscope/)
+//!
+//! # Example
+//!
+//! ```rust
+//! use eo_identifiers::identifiers::planet::Scene;
+//! use std::str::FromStr;
+//!
+//! assert!(
+//!     Scene::from_str("20210602_143854_1003_3B_AnalyticMS")
+//!     .is_ok()
+//! );
+//! ```
 // https://support.planet.com/hc/en-us/articles/213169607-How-can-we-interpret-image-file-names-and-ID-numbers-
 // https://developers.planet.com/docs/data/planetscope/
+use crate::common_parsers::{
+    is_char_alphanumeric, parse_simple_date, parse_simple_time, take_alphanumeric_n, take_n_digits,
+};
+use crate::impl_from_str;
+use alloc::format;
+use alloc::string::{String, ToString};
+use chrono::NaiveDateTime;
+use core::fmt;
+use nom::bytes::complete::{take_while1, take_while_m_n};
+use nom::character::complete::char;
+use nom::combinator::opt;
+use nom::sequence::{preceded, terminated, tuple};
+use nom::IResult;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-//! TODO
+/// PlanetScope scene
+///
+/// Either the bare scene id (`20210602_143854_1003`) or the name of an asset of the
+/// scene, which appends the product type and the asset type
+/// (`20210602_143854_1003_3B_AnalyticMS`). Newer scene ids carry the fraction of the
+/// second of the acquisition before the satellite id (`20220101_151123_66_2426`).
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Scene {
+    /// acquisition datetime
+    pub acquire_datetime: NaiveDateTime,
+
+    /// hundredths of the second of the acquisition, only part of newer scene ids
+    pub fraction_of_second: Option<u8>,
+
+    /// satellite id, lowercase hexadecimal
+    pub satellite_id: String,
+
+    /// product type, the processing level, for example `3B` for ortho scenes
+    pub product_type: Option<String>,
+
+    /// asset type, for example `AnalyticMS` or `AnalyticMS_SR`
+    ///
+    /// Kept in the case used by Planet.
+    pub asset_type: Option<String>,
+}
+
+impl fmt::Display for Scene {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}_", self.acquire_datetime.format("%Y%m%d_%H%M%S"))?;
+        if let Some(fraction_of_second) = self.fraction_of_second {
+            write!(f, "{:02}_", fraction_of_second)?;
+        }
+        f.write_str(&self.satellite_id)?;
+        if let Some(product_type) = &self.product_type {
+            write!(f, "_{}", product_type)?;
+            if let Some(asset_type) = &self.asset_type {
+                write!(f, "_{}", asset_type)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn consume_product_sep(s: &str) -> IResult<&str, core::primitive::char> {
+    char('_')(s)
+}
+
+fn parse_product_type(s: &str) -> IResult<&str, String> {
+    let (s, (level, sub_level)) = tuple((
+        take_while_m_n(1, 1, |c: char| c.is_ascii_digit()),
+        take_while_m_n(1, 1, |c: char| c.is_ascii_alphabetic()),
+    ))(s)?;
+    Ok((s, format!("{}{}", level, sub_level.to_uppercase())))
+}
+
+fn parse_asset_type(s: &str) -> IResult<&str, String> {
+    let (s, asset_type) = take_while1(|c| is_char_alphanumeric(c) || c == '_')(s)?;
+    Ok((s, asset_type.to_string()))
+}
+
+/// nom parser function
+pub fn parse_scene(s: &str) -> IResult<&str, Scene> {
+    let (s, date) = parse_simple_date(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, time) = parse_simple_time(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, fraction_of_second) = opt(terminated(take_n_digits(2), consume_product_sep))(s)?;
+    let (s, satellite_id) = take_alphanumeric_n(4)(s)?;
+    let (s, product_type) = opt(preceded(consume_product_sep, parse_product_type))(s)?;
+    let (s, asset_type) = if product_type.is_some() {
+        opt(preceded(consume_product_sep, parse_asset_type))(s)?
+    } else {
+        (s, None)
+    };
+
+    Ok((
+        s,
+        Scene {
+            acquire_datetime: NaiveDateTime::new(date, time),
+            fraction_of_second,
+            satellite_id: satellite_id.to_lowercase(),
+            product_type,
+            asset_type,
+        },
+    ))
+}
+
+impl_from_str!(parse_scene, Scene);
+
+#[cfg(test)]
+mod tests {
+    use crate::identifiers::planet::parse_scene;
+    use crate::identifiers::tests::apply_to_samples_from_txt;
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+    #[test]
+    fn parse_planet_scene() {
+        let (_, scene) = parse_scene("20210602_143854_1003").unwrap();
+        assert_eq!(
+            scene.acquire_datetime,
+            NaiveDateTime::new(
+                NaiveDate::from_ymd_opt(2021, 6, 2).unwrap(),
+                NaiveTime::from_hms_opt(14, 38, 54).unwrap()
+            )
+        );
+        assert_eq!(scene.satellite_id.as_str(), "1003");
+        assert_eq!(scene.fraction_of_second, None);
+        assert_eq!(scene.product_type, None);
+        assert_eq!(scene.asset_type, None);
+    }
+
+    #[test]
+    fn parse_planet_scene_asset() {
+        let (rest, scene) = parse_scene("20210602_143854_1003_3B_AnalyticMS_SR.tif").unwrap();
+        assert_eq!(rest, ".tif");
+        assert_eq!(scene.satellite_id.as_str(), "1003");
+        assert_eq!(scene.product_type.as_deref(), Some("3B"));
+        assert_eq!(scene.asset_type.as_deref(), Some("AnalyticMS_SR"));
+    }
+
+    #[test]
+    fn parse_planet_scene_fraction_of_second() {
+        let (rest, scene) = parse_scene("20220101_151123_66_2426").unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(scene.fraction_of_second, Some(66));
+        assert_eq!(scene.satellite_id.as_str(), "2426");
+        assert_eq!(scene.product_type, None);
+
+        let (_, scene) = parse_scene("20220101_151123_06_241d_3B_AnalyticMS_SR").unwrap();
+        assert_eq!(scene.fraction_of_second, Some(6));
+        assert_eq!(scene.satellite_id.as_str(), "241d");
+        assert_eq!(scene.product_type.as_deref(), Some("3B"));
+        assert_eq!(scene.asset_type.as_deref(), Some("AnalyticMS_SR"));
+        assert_eq!(
+            scene.to_string(),
+            "20220101_151123_06_241d_3B_AnalyticMS_SR"
+        );
+    }
+
+    #[test]
+    fn apply_to_scene_testdata() {
+        apply_to_samples_from_txt("planet_scenes.txt", |s| {
+            parse_scene(s).unwrap();
+        })
+    }
+
+    #[test]
+    fn scene_display_roundtrip() {
+        apply_to_samples_from_txt("planet_scenes.txt", |s| {
+            let (_, scene) = parse_scene(s).unwrap();
+            assert_eq!(scene.to_string(), s.trim_end_matches(".tif"));
+        })
+    }
+}
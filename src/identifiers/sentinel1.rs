@@ -16,9 +16,18 @@
 //! );
 //! ```
 //!
-use crate::common_parsers::{parse_esa_timestamp, take_n_digits_in_range};
-use crate::{impl_from_str, Mission};
+use crate::common_parsers::{
+    format_esa_timestamp, parse_container_extension, parse_esa_timestamp, take_n_digits_in_range,
+    SAFE_EXTENSION,
+};
+use crate::{impl_from_str, Mission, Name, NameLong, ParseError};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
 use chrono::NaiveDateTime;
+use core::fmt;
+use core::str::FromStr;
 use nom::branch::alt;
 use nom::bytes::complete::{tag, tag_no_case, take_while_m_n};
 use nom::character::complete::char;
@@ -34,6 +43,32 @@ pub enum MissionId {
     S1B,
 }
 
+impl MissionId {
+    /// code as used in identifiers
+    pub fn code(&self) -> &'static str {
+        match self {
+            MissionId::S1A => "S1A",
+            MissionId::S1B => "S1B",
+        }
+    }
+
+    /// relative orbit of the given absolute orbit
+    ///
+    /// The repeat cycle of each satellite consists of 175 orbits. The relative orbit is
+    /// computed with `(absolute - offset) mod 175 + 1`, with an offset of 73 for S1A and
+    /// 27 for S1B.
+    pub fn relative_orbit(&self, absolute_orbit: u32) -> u16 {
+        let offset = match self {
+            MissionId::S1A => 73,
+            MissionId::S1B => 27,
+        };
+        ((absolute_orbit as i64 - offset).rem_euclid(ORBITS_PER_CYCLE) + 1) as u16
+    }
+}
+
+/// number of orbits in the repeat cycle of a satellite
+const ORBITS_PER_CYCLE: i64 = 175;
+
 impl From<MissionId> for Mission {
     fn from(_: MissionId) -> Self {
         Mission::Sentinel1
@@ -54,6 +89,93 @@ pub enum Mode {
     S6,
 }
 
+impl Mode {
+    /// code as used in identifiers
+    pub fn code(&self) -> &'static str {
+        match self {
+            Mode::IW => "IW",
+            Mode::EW => "EW",
+            Mode::WV => "WV",
+            Mode::S1 => "S1",
+            Mode::S2 => "S2",
+            Mode::S3 => "S3",
+            Mode::S4 => "S4",
+            Mode::S5 => "S5",
+            Mode::S6 => "S6",
+        }
+    }
+
+    /// acquisition mode, the S1-S6 beams belong to stripmap mode
+    pub fn acquisition_mode(&self) -> AcquisitionMode {
+        match self {
+            Mode::IW => AcquisitionMode::IW,
+            Mode::EW => AcquisitionMode::EW,
+            Mode::WV => AcquisitionMode::WV,
+            Mode::S1 | Mode::S2 | Mode::S3 | Mode::S4 | Mode::S5 | Mode::S6 => AcquisitionMode::SM,
+        }
+    }
+}
+
+/// acquisition mode of the SAR instrument
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AcquisitionMode {
+    /// Stripmap
+    SM,
+
+    /// Interferometric Wide Swath
+    IW,
+
+    /// Extra Wide Swath
+    EW,
+
+    /// Wave
+    WV,
+
+    Other(String),
+}
+
+impl Name for AcquisitionMode {
+    fn name(&self) -> &str {
+        match self {
+            AcquisitionMode::SM => "SM",
+            AcquisitionMode::IW => "IW",
+            AcquisitionMode::EW => "EW",
+            AcquisitionMode::WV => "WV",
+            AcquisitionMode::Other(s) => s.as_str(),
+        }
+    }
+}
+
+impl NameLong for AcquisitionMode {
+    fn name_long(&self) -> &str {
+        match self {
+            AcquisitionMode::SM => "Stripmap",
+            AcquisitionMode::IW => "Interferometric Wide Swath",
+            AcquisitionMode::EW => "Extra Wide Swath",
+            AcquisitionMode::WV => "Wave",
+            AcquisitionMode::Other(s) => s.as_str(),
+        }
+    }
+}
+
+impl FromStr for AcquisitionMode {
+    type Err = ParseError;
+
+    /// parse the two letter code of the mode, unknown codes are kept as `Other`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code = s.to_uppercase();
+        Ok(match code.as_str() {
+            "" => return Err(ParseError::NotEnoughData(0)),
+            "SM" => AcquisitionMode::SM,
+            "IW" => AcquisitionMode::IW,
+            "EW" => AcquisitionMode::EW,
+            "WV" => AcquisitionMode::WV,
+            _ => AcquisitionMode::Other(code),
+        })
+    }
+}
+
 #[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Copy, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ProductType {
@@ -63,6 +185,18 @@ pub enum ProductType {
     OCN,
 }
 
+impl ProductType {
+    /// code as used in identifiers
+    pub fn code(&self) -> &'static str {
+        match self {
+            ProductType::RAW => "RAW",
+            ProductType::SLC => "SLC",
+            ProductType::GRD => "GRD",
+            ProductType::OCN => "OCN",
+        }
+    }
+}
+
 #[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Copy, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ResolutionClass {
@@ -72,6 +206,18 @@ pub enum ResolutionClass {
     NotApplicable,
 }
 
+impl ResolutionClass {
+    /// single letter code as used in identifiers
+    pub fn code(&self) -> char {
+        match self {
+            ResolutionClass::Full => 'F',
+            ResolutionClass::High => 'H',
+            ResolutionClass::Medium => 'M',
+            ResolutionClass::NotApplicable => '_',
+        }
+    }
+}
+
 #[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Copy, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ProcessingLevel {
@@ -80,6 +226,17 @@ pub enum ProcessingLevel {
     Level2,
 }
 
+impl ProcessingLevel {
+    /// single digit code as used in identifiers
+    pub fn code(&self) -> char {
+        match self {
+            ProcessingLevel::Level0 => '0',
+            ProcessingLevel::Level1 => '1',
+            ProcessingLevel::Level2 => '2',
+        }
+    }
+}
+
 #[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Copy, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ProductClass {
@@ -87,6 +244,16 @@ pub enum ProductClass {
     Annotation,
 }
 
+impl ProductClass {
+    /// single letter code as used in identifiers
+    pub fn code(&self) -> char {
+        match self {
+            ProductClass::Standard => 'S',
+            ProductClass::Annotation => 'A',
+        }
+    }
+}
+
 #[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Copy, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ProductPolarisation {
@@ -94,6 +261,34 @@ pub enum ProductPolarisation {
     VV,
     HHHV,
     VVVH,
+
+    /// partial dual, HH only
+    PartialHH,
+
+    /// partial dual, HV only
+    PartialHV,
+
+    /// partial dual, VV only
+    PartialVV,
+
+    /// partial dual, VH only
+    PartialVH,
+}
+
+impl ProductPolarisation {
+    /// code as used in identifiers
+    pub fn code(&self) -> &'static str {
+        match self {
+            ProductPolarisation::HH => "SH",
+            ProductPolarisation::VV => "SV",
+            ProductPolarisation::HHHV => "DH",
+            ProductPolarisation::VVVH => "DV",
+            ProductPolarisation::PartialHH => "HH",
+            ProductPolarisation::PartialHV => "HV",
+            ProductPolarisation::PartialVV => "VV",
+            ProductPolarisation::PartialVH => "VH",
+        }
+    }
 }
 
 /// Sentinel 1 Product
@@ -157,6 +352,10 @@ pub struct Product {
     /// > * SV (single VV polarisation)
     /// > * DH (dual HH+HV polarisation)
     /// > * DV (dual VV+VH polarisation)
+    /// > * HH (partial dual, HH only)
+    /// > * HV (partial dual, HV only)
+    /// > * VV (partial dual, VV only)
+    /// > * VH (partial dual, VH only)
     pub polarisation: ProductPolarisation,
 
     /// start datetime
@@ -187,7 +386,40 @@ pub struct Product {
     /// > The product unique identifier (CCCC) is a hexadecimal string generated by
     /// > computing CRC-16 on the manifest file using CRC-CCITT.
     pub product_unique_identifier: String,
-    // folder extension is skipped
+
+    /// extension of the `.SAFE` directory the product is distributed in, without the dot
+    pub container_extension: Option<String>,
+}
+
+impl Product {
+    /// The individual polarisations contained in the product.
+    ///
+    /// Dual polarisation products are expanded into both of their polarisations,
+    /// `DV` becomes `[VV, VH]` and `DH` becomes `[HH, HV]`.
+    pub fn polarisations(&self) -> Vec<DatasetPolarisation> {
+        match self.polarisation {
+            ProductPolarisation::HH | ProductPolarisation::PartialHH => {
+                vec![DatasetPolarisation::HH]
+            }
+            ProductPolarisation::VV | ProductPolarisation::PartialVV => {
+                vec![DatasetPolarisation::VV]
+            }
+            ProductPolarisation::PartialHV => vec![DatasetPolarisation::HV],
+            ProductPolarisation::PartialVH => vec![DatasetPolarisation::VH],
+            ProductPolarisation::HHHV => vec![DatasetPolarisation::HH, DatasetPolarisation::HV],
+            ProductPolarisation::VVVH => vec![DatasetPolarisation::VV, DatasetPolarisation::VH],
+        }
+    }
+
+    /// relative orbit, computed from the absolute orbit number
+    pub fn relative_orbit(&self) -> u16 {
+        self.mission_id.relative_orbit(self.orbit_number)
+    }
+
+    /// acquisition mode of the product
+    pub fn acquisition_mode(&self) -> AcquisitionMode {
+        self.mode.acquisition_mode()
+    }
 }
 
 #[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Hash, Copy)]
@@ -215,6 +447,31 @@ pub enum SwathIdentifier {
 }
 
 impl SwathIdentifier {
+    /// code as used in identifiers
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::S1 => "S1",
+            Self::S2 => "S2",
+            Self::S3 => "S3",
+            Self::S4 => "S4",
+            Self::S5 => "S5",
+            Self::S6 => "S6",
+            Self::IW => "IW",
+            Self::IW1 => "IW1",
+            Self::IW2 => "IW2",
+            Self::IW3 => "IW3",
+            Self::EW => "EW",
+            Self::EW1 => "EW1",
+            Self::EW2 => "EW2",
+            Self::EW3 => "EW3",
+            Self::EW4 => "EW4",
+            Self::EW5 => "EW5",
+            Self::WV => "WV",
+            Self::WV1 => "WV1",
+            Self::WV2 => "WV2",
+        }
+    }
+
     pub fn is_s(&self) -> bool {
         matches!(
             self,
@@ -227,12 +484,41 @@ impl SwathIdentifier {
     }
 
     pub fn is_ew(&self) -> bool {
-        matches!(self, Self::EW1 | Self::EW2 | Self::EW3 | Self::EW)
+        matches!(
+            self,
+            Self::EW1 | Self::EW2 | Self::EW3 | Self::EW4 | Self::EW5 | Self::EW
+        )
     }
 
     pub fn is_wv(&self) -> bool {
         matches!(self, Self::WV1 | Self::WV2 | Self::WV)
     }
+
+    /// acquisition mode the beam or sub-swath belongs to
+    pub fn acquisition_mode(&self) -> AcquisitionMode {
+        match self {
+            swath if swath.is_s() => AcquisitionMode::SM,
+            swath if swath.is_iw() => AcquisitionMode::IW,
+            swath if swath.is_ew() => AcquisitionMode::EW,
+            _ => AcquisitionMode::WV,
+        }
+    }
+
+    /// number of the beam or sub-swath, if the identifier refers to a single one
+    ///
+    /// For wave mode this is the vignette beam (`wv1` = 1, `wv2` = 2). Product names only
+    /// carry the mode, so the beam is only available for datasets.
+    pub fn beam_number(&self) -> Option<u8> {
+        match self {
+            Self::S1 | Self::IW1 | Self::EW1 | Self::WV1 => Some(1),
+            Self::S2 | Self::IW2 | Self::EW2 | Self::WV2 => Some(2),
+            Self::S3 | Self::IW3 | Self::EW3 => Some(3),
+            Self::S4 | Self::EW4 => Some(4),
+            Self::S5 | Self::EW5 => Some(5),
+            Self::S6 => Some(6),
+            Self::IW | Self::EW | Self::WV => None,
+        }
+    }
 }
 
 #[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Copy, Hash)]
@@ -244,6 +530,18 @@ pub enum DatasetPolarisation {
     VH,
 }
 
+impl DatasetPolarisation {
+    /// code as used in identifiers
+    pub fn code(&self) -> &'static str {
+        match self {
+            DatasetPolarisation::HH => "HH",
+            DatasetPolarisation::VV => "VV",
+            DatasetPolarisation::HV => "HV",
+            DatasetPolarisation::VH => "VH",
+        }
+    }
+}
+
 /// Sentinel 1 Dataset
 ///
 /// Based on the [official S1 naming convention](https://sentinel.esa.int/web/sentinel/user-guides/sentinel-1-sar/naming-conventions).
@@ -314,6 +612,41 @@ pub struct Dataset {
     pub image_number: u32,
 }
 
+impl Dataset {
+    /// relative orbit, computed from the absolute orbit number
+    pub fn relative_orbit(&self) -> u16 {
+        self.mission_id.relative_orbit(self.orbit_number)
+    }
+
+    /// acquisition mode of the dataset
+    pub fn acquisition_mode(&self) -> AcquisitionMode {
+        self.swath_identifier.acquisition_mode()
+    }
+
+    /// prefix shared by the names of the products containing this dataset, for
+    /// example `S1A_IW_GRD`
+    ///
+    /// Product names only carry the mode instead of the sub-swath. The fields following the
+    /// product type - resolution, processing level, class and polarisation - are specific
+    /// to the processing and the sensing times of a dataset may differ from the product, so
+    /// they can not be derived. Suitable to glob-match (`S1A_IW_GRD*`) the products of a
+    /// dataset.
+    pub fn product_name_prefix(&self) -> String {
+        let mode = match self.swath_identifier {
+            swath if swath.is_iw() => "IW",
+            swath if swath.is_ew() => "EW",
+            swath if swath.is_wv() => "WV",
+            swath => swath.code(),
+        };
+        format!(
+            "{}_{}_{}",
+            self.mission_id.code(),
+            mode,
+            self.product_type.code()
+        )
+    }
+}
+
 fn is_not_product_sep(c: core::primitive::char) -> bool {
     c != '_'
 }
@@ -386,6 +719,10 @@ fn parse_product_polarisation(s: &str) -> IResult<&str, ProductPolarisation> {
         map(tag_no_case("sv"), |_| ProductPolarisation::VV),
         map(tag_no_case("dh"), |_| ProductPolarisation::HHHV),
         map(tag_no_case("dv"), |_| ProductPolarisation::VVVH),
+        map(tag_no_case("hh"), |_| ProductPolarisation::PartialHH),
+        map(tag_no_case("hv"), |_| ProductPolarisation::PartialHV),
+        map(tag_no_case("vv"), |_| ProductPolarisation::PartialVV),
+        map(tag_no_case("vh"), |_| ProductPolarisation::PartialVH),
     ))(s)
 }
 
@@ -411,6 +748,7 @@ pub fn parse_product(s: &str) -> IResult<&str, Product> {
     let (s, data_take_identifier) = take_while_m_n(6, 6, is_not_product_sep)(s)?;
     let (s, _) = consume_product_sep(s)?;
     let (s, product_unique_identifier) = take_while_m_n(4, 4, is_not_product_sep)(s)?;
+    let (s, container_extension) = parse_container_extension(SAFE_EXTENSION)(s)?;
 
     Ok((
         s,
@@ -427,6 +765,7 @@ pub fn parse_product(s: &str) -> IResult<&str, Product> {
             orbit_number,
             data_take_identifier: data_take_identifier.to_uppercase(),
             product_unique_identifier: product_unique_identifier.to_uppercase(),
+            container_extension,
         },
     ))
 }
@@ -503,13 +842,59 @@ pub fn parse_dataset(s: &str) -> IResult<&str, Dataset> {
 impl_from_str!(parse_dataset, Dataset);
 impl_from_str!(parse_product, Product);
 
+impl fmt::Display for Product {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}_{}_{}{}_{}{}{}_{}_{}_{:06}_{}_{}",
+            self.mission_id.code(),
+            self.mode.code(),
+            self.product_type.code(),
+            self.resolution_class.code(),
+            self.processing_level.code(),
+            self.product_class.code(),
+            self.polarisation.code(),
+            format_esa_timestamp(&self.start_datetime),
+            format_esa_timestamp(&self.stop_datetime),
+            self.orbit_number,
+            self.data_take_identifier,
+            self.product_unique_identifier
+        )?;
+        if let Some(container_extension) = &self.container_extension {
+            write!(f, ".{}", container_extension)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Dataset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = format!(
+            "{}-{}-{}-{}-{}-{}-{:06}-{}-{:03}",
+            self.mission_id.code(),
+            self.swath_identifier.code(),
+            self.product_type.code(),
+            self.polarisation.code(),
+            format_esa_timestamp(&self.start_datetime),
+            format_esa_timestamp(&self.stop_datetime),
+            self.orbit_number,
+            self.data_take_identifier,
+            self.image_number
+        );
+        write!(f, "{}", s.to_lowercase())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::identifiers::sentinel1::{
-        parse_dataset, parse_product, DatasetPolarisation, MissionId, Mode, ProcessingLevel,
-        ProductClass, ProductPolarisation, ProductType, ResolutionClass, SwathIdentifier,
+        parse_dataset, parse_product, AcquisitionMode, DatasetPolarisation, MissionId, Mode,
+        ProcessingLevel, ProductClass, ProductPolarisation, ProductType, ResolutionClass,
+        SwathIdentifier,
     };
     use crate::identifiers::tests::apply_to_samples_from_txt;
+    use crate::{Name, NameLong};
+    use std::str::FromStr;
 
     #[test]
     fn parse_s1_product() {
@@ -527,6 +912,169 @@ mod tests {
         assert_eq!(product.orbit_number, 31142);
         assert_eq!(product.data_take_identifier.as_str(), "039466");
         assert_eq!(product.product_unique_identifier.as_str(), "A237");
+        assert_eq!(product.container_extension, None);
+    }
+
+    #[test]
+    fn acquisition_modes() {
+        for (name, mode) in [
+            (
+                "S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237",
+                AcquisitionMode::IW,
+            ),
+            (
+                "S1A_EW_GRDM_1SDH_20151221T165227_20151221T165332_009143_00D275_8694",
+                AcquisitionMode::EW,
+            ),
+            (
+                "S1A_WV_OCN__2SSV_20221028T001106_20221028T003120_045639_057514_13AD",
+                AcquisitionMode::WV,
+            ),
+            (
+                "S1A_S3_GRDH_1SDV_20221028T083622_20221028T083646_045644_05753C_0C6F",
+                AcquisitionMode::SM,
+            ),
+        ] {
+            let (_, product) = parse_product(name).unwrap();
+            assert_eq!(product.acquisition_mode(), mode);
+        }
+
+        for (name, mode) in [
+            (
+                "s1a-iw1-slc-vv-20221029t171426-20221029t171451-045660-0575ce-004",
+                AcquisitionMode::IW,
+            ),
+            (
+                "s1a-ew5-slc-hh-20151221t165227-20151221t165332-009143-00d275-005",
+                AcquisitionMode::EW,
+            ),
+            (
+                "s1a-wv2-slc-vv-20221028t001106-20221028t003120-045639-057514-002",
+                AcquisitionMode::WV,
+            ),
+            (
+                "s1a-s3-grd-vh-20221028t083622-20221028t083646-045644-05753c-002",
+                AcquisitionMode::SM,
+            ),
+        ] {
+            let (_, dataset) = parse_dataset(name).unwrap();
+            assert_eq!(dataset.acquisition_mode(), mode);
+        }
+
+        assert_eq!(AcquisitionMode::IW.name(), "IW");
+        assert_eq!(
+            AcquisitionMode::IW.name_long(),
+            "Interferometric Wide Swath"
+        );
+        assert_eq!(AcquisitionMode::SM.name_long(), "Stripmap");
+        assert_eq!(
+            AcquisitionMode::from_str("ew").unwrap(),
+            AcquisitionMode::EW
+        );
+        assert_eq!(
+            AcquisitionMode::from_str("zz").unwrap(),
+            AcquisitionMode::Other("ZZ".to_string())
+        );
+        assert!(AcquisitionMode::from_str("").is_err());
+    }
+
+    #[test]
+    fn parse_s1_product_class_and_polarisation() {
+        let (_, product) =
+            parse_product("S1A_IW_SLC__1SDV_20200207T051835_20200207T051902_031142_039466_1C5A")
+                .unwrap();
+        assert_eq!(product.product_type, ProductType::SLC);
+        assert_eq!(product.product_class, ProductClass::Standard);
+        assert_eq!(product.polarisation, ProductPolarisation::VVVH);
+
+        let (_, product) =
+            parse_product("S1B_EW_GRDM_1ASH_20191220T051010_20191220T051110_019435_024B4E_8C36")
+                .unwrap();
+        assert_eq!(product.product_class, ProductClass::Annotation);
+        assert_eq!(product.polarisation, ProductPolarisation::HH);
+
+        let (_, product) =
+            parse_product("S1A_IW_GRDH_1SHV_20200207T051836_20200207T051901_031142_039466_A237")
+                .unwrap();
+        assert_eq!(product.polarisation, ProductPolarisation::PartialHV);
+        assert_eq!(product.polarisations(), vec![DatasetPolarisation::HV]);
+        assert_eq!(
+            product.to_string(),
+            "S1A_IW_GRDH_1SHV_20200207T051836_20200207T051901_031142_039466_A237"
+        );
+    }
+
+    #[test]
+    fn parse_s1_product_container_extension() {
+        let name = "S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237";
+        let s = format!("{}.SAFE", name);
+        let (rest, product) = parse_product(&s).unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(product.container_extension.as_deref(), Some("SAFE"));
+        assert_eq!(product.product_unique_identifier.as_str(), "A237");
+        assert_eq!(product.to_string(), format!("{}.SAFE", name));
+    }
+
+    #[test]
+    fn product_polarisations_single() {
+        let (_, product) =
+            parse_product("S1A_EW_GRDM_1SSH_20151221T165227_20151221T165332_009143_00D275_8694")
+                .unwrap();
+        assert_eq!(product.polarisations(), vec![DatasetPolarisation::HH]);
+    }
+
+    #[test]
+    fn product_polarisations_dual() {
+        let (_, product) =
+            parse_product("S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237")
+                .unwrap();
+        assert_eq!(
+            product.polarisations(),
+            vec![DatasetPolarisation::VV, DatasetPolarisation::VH]
+        );
+
+        let (_, product) =
+            parse_product("S1A_EW_GRDM_1SDH_20151221T165227_20151221T165332_009143_00D275_8694")
+                .unwrap();
+        assert_eq!(
+            product.polarisations(),
+            vec![DatasetPolarisation::HH, DatasetPolarisation::HV]
+        );
+    }
+
+    #[test]
+    fn dataset_product_name_prefix() {
+        let (_, dataset) =
+            parse_dataset("s1a-iw-grd-vh-20221029t171425-20221029t171450-045660-0575ce-002")
+                .unwrap();
+        let (_, product) =
+            parse_product("S1A_IW_GRDH_1SDV_20221029T171425_20221029T171450_045660_0575CE_5B3B")
+                .unwrap();
+        assert_eq!(dataset.product_name_prefix(), "S1A_IW_GRD");
+        assert!(product
+            .to_string()
+            .starts_with(&dataset.product_name_prefix()));
+
+        let (_, dataset) =
+            parse_dataset("s1b-ew2-slc-hh-20200101t000000-20200101t000100-019000-023a1b-002")
+                .unwrap();
+        assert_eq!(dataset.product_name_prefix(), "S1B_EW_SLC");
+        assert!(SwathIdentifier::EW5.is_ew());
+    }
+
+    #[test]
+    fn relative_orbit() {
+        assert_eq!(MissionId::S1A.relative_orbit(73), 1);
+        assert_eq!(MissionId::S1A.relative_orbit(247), 175);
+        assert_eq!(MissionId::S1A.relative_orbit(248), 1);
+        assert_eq!(MissionId::S1A.relative_orbit(1), 104);
+        assert_eq!(MissionId::S1B.relative_orbit(27), 1);
+        assert_eq!(MissionId::S1B.relative_orbit(26), 175);
+
+        let (_, product) =
+            parse_product("S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237")
+                .unwrap();
+        assert_eq!(product.relative_orbit(), 95);
     }
 
     #[test]
@@ -543,6 +1091,43 @@ mod tests {
         assert_eq!(ds.data_take_identifier.as_str(), "0575CE");
     }
 
+    #[test]
+    fn parse_s1_wv() {
+        let (_, product) =
+            parse_product("S1A_WV_SLC__1SSV_20221030T081623_20221030T082131_045672_057641_9C15")
+                .unwrap();
+        assert_eq!(product.mode, Mode::WV);
+
+        let (_, ds) =
+            parse_dataset("s1a-wv2-slc-vv-20221030t081638-20221030t081641-045672-057641-002.tiff")
+                .unwrap();
+        assert_eq!(ds.swath_identifier, SwathIdentifier::WV2);
+        assert!(ds.swath_identifier.is_wv());
+        assert_eq!(ds.swath_identifier.beam_number(), Some(2));
+        assert_eq!(SwathIdentifier::WV.beam_number(), None);
+    }
+
+    #[test]
+    fn data_take_identifier_roundtrip() {
+        let name = "S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_009466_A237";
+        let (_, product) = parse_product(name).unwrap();
+        assert_eq!(product.data_take_identifier.as_str(), "009466");
+        assert_eq!(product.to_string(), name);
+
+        let name = "s1a-iw-grd-vh-20221029t171425-20221029t171450-045660-00a5ce-002";
+        let (_, ds) = parse_dataset(name).unwrap();
+        assert_eq!(ds.data_take_identifier.as_str(), "00A5CE");
+        assert_eq!(ds.to_string(), name);
+    }
+
+    #[test]
+    fn product_display_roundtrip() {
+        apply_to_samples_from_txt("sentinel1_products.txt", |s| {
+            let (_, product) = parse_product(s).unwrap();
+            assert_eq!(product.to_string(), s);
+        })
+    }
+
     #[test]
     fn parse_s1_dataset_no_fileextension() {
         let (_, _ds) =
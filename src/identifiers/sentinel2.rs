@@ -11,15 +11,27 @@
 //!     .is_ok()
 //! );
 //! ```
-use chrono::NaiveDateTime;
+use alloc::string::String;
+use alloc::vec::Vec;
+use chrono::{Duration, NaiveDate, NaiveDateTime};
 use nom::branch::alt;
 use nom::bytes::complete::tag_no_case;
 use nom::character::complete::char;
-use nom::combinator::map;
+use nom::combinator::{map, opt};
+use nom::sequence::preceded;
 use nom::IResult;
+// float math of libm, unused when another crate in the build links std
+#[cfg(not(any(feature = "std", test)))]
+#[allow(unused_imports)]
+use num_traits::Float;
 
-use crate::common_parsers::{parse_esa_timestamp, take_alphanumeric_n, take_n_digits_in_range};
-use crate::{impl_from_str, Mission};
+use crate::common_parsers::{
+    format_esa_timestamp, parse_container_extension, parse_esa_timestamp, take_alphanumeric_n,
+    take_n_digits_in_range, SAFE_EXTENSION,
+};
+use crate::{impl_from_str, Capabilities, JulianDate, Mission, Name, NameLong};
+use core::fmt;
+use core::str::FromStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -30,6 +42,53 @@ pub enum MissionId {
     S2B,
 }
 
+impl MissionId {
+    /// code as used in identifiers
+    pub fn code(&self) -> &'static str {
+        match self {
+            MissionId::S2A => "S2A",
+            MissionId::S2B => "S2B",
+        }
+    }
+}
+
+impl Name for MissionId {
+    fn name(&self) -> &str {
+        self.code()
+    }
+}
+
+impl NameLong for MissionId {
+    fn name_long(&self) -> &str {
+        match self {
+            MissionId::S2A => "Sentinel-2A",
+            MissionId::S2B => "Sentinel-2B",
+        }
+    }
+}
+
+/// number of orbits in a repeat cycle
+const ORBITS_PER_CYCLE: u32 = 143;
+
+/// duration of a repeat cycle, in days
+const CYCLE_DAYS: i64 = 10;
+
+impl MissionId {
+    /// launch datetime and a reference absolute orbit with the datetime the satellite
+    /// passed it
+    fn orbit_reference(&self) -> (NaiveDateTime, u32, NaiveDateTime) {
+        let dt = |y, m, d, h, mn, s| {
+            NaiveDate::from_ymd_opt(y, m, d)
+                .and_then(|date| date.and_hms_opt(h, mn, s))
+                .expect("valid datetime")
+        };
+        match self {
+            MissionId::S2A => (dt(2015, 6, 23, 1, 51, 58), 595, dt(2015, 8, 3, 10, 10, 16)),
+            MissionId::S2B => (dt(2017, 3, 7, 1, 49, 24), 6458, dt(2018, 6, 3, 10, 10, 19)),
+        }
+    }
+}
+
 impl From<MissionId> for Mission {
     fn from(_: MissionId) -> Self {
         Mission::Sentinel2
@@ -43,6 +102,31 @@ pub enum ProductLevel {
     L2A,
 }
 
+impl ProductLevel {
+    /// code as used in identifiers
+    pub fn code(&self) -> &'static str {
+        match self {
+            ProductLevel::L1C => "L1C",
+            ProductLevel::L2A => "L2A",
+        }
+    }
+}
+
+impl Name for ProductLevel {
+    fn name(&self) -> &str {
+        self.code()
+    }
+}
+
+impl NameLong for ProductLevel {
+    fn name_long(&self) -> &str {
+        match self {
+            ProductLevel::L1C => "Level-1C Top-Of-Atmosphere reflectance",
+            ProductLevel::L2A => "Level-2A Bottom-Of-Atmosphere reflectance",
+        }
+    }
+}
+
 /// Sentinel 2 product
 ///
 /// New format Naming Convention for Sentinel-2 Level-1C products generated after 6 December 2016:
@@ -74,7 +158,267 @@ pub struct Product {
     /// Used to distinguish between different end user products from the same datatake.
     /// Depending on the instance, the time in this field can be earlier or slightly later than
     /// the datatake sensing time.
-    pub product_discriminator: String,
+    ///
+    /// Omitted by some derived products.
+    pub product_discriminator: Option<String>,
+
+    /// extension of the `.SAFE` directory the product is distributed in, without the dot
+    pub container_extension: Option<String>,
+}
+
+/// spectral bands of the MSI instrument, contained in all products
+const MSI_BANDS: [&str; 13] = [
+    "B01", "B02", "B03", "B04", "B05", "B06", "B07", "B08", "B8A", "B09", "B10", "B11", "B12",
+];
+
+/// static facts about the MultiSpectral Instrument (MSI)
+pub const MSI_CAPABILITIES: Capabilities = Capabilities {
+    spectral_bands: 13,
+    has_thermal_bands: false,
+    swath_width_km: 290,
+};
+
+/// layers only contained in L2A products: aerosol optical thickness, water vapour and
+/// the scene classification
+const L2A_LAYERS: [&str; 3] = ["AOT", "WVP", "SCL"];
+
+impl Product {
+    /// canonical name of the product, without the `.SAFE` extension
+    pub fn to_identifier_string(&self) -> String {
+        let mut s = String::new();
+        self.write_identifier(&mut s)
+            .expect("writing to a String does not fail");
+        s
+    }
+
+    /// write the identifier without the container extension
+    fn write_identifier<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        write!(
+            w,
+            "{}_MSI{}_{}_N{:02}{:02}_R{:03}_T{}",
+            self.mission_id.code(),
+            self.product_level.code(),
+            format_esa_timestamp(&self.start_datetime),
+            self.pdgs_baseline_number.0,
+            self.pdgs_baseline_number.1,
+            self.relative_orbit_number,
+            self.tile_number
+        )?;
+        if let Some(product_discriminator) = &self.product_discriminator {
+            write!(w, "_{}", product_discriminator)?;
+        }
+        Ok(())
+    }
+
+    /// the product discriminator as datetime
+    ///
+    /// The discriminator usually is the generation time of the product, but it may also be
+    /// the sensing time of the datastrip.
+    pub fn discriminator_datetime(&self) -> Option<NaiveDateTime> {
+        let (_, dt) = parse_esa_timestamp(self.product_discriminator.as_deref()?).ok()?;
+        Some(dt)
+    }
+
+    /// names of the bands and layers contained in the product
+    ///
+    /// L2A products additionally contain the `AOT`, `WVP` and `SCL` (scene classification)
+    /// layers. The `B10` cirrus band is not part of L2A products.
+    pub fn bands(&self) -> Vec<&'static str> {
+        match self.product_level {
+            ProductLevel::L1C => MSI_BANDS.to_vec(),
+            ProductLevel::L2A => MSI_BANDS
+                .iter()
+                .filter(|band| **band != "B10")
+                .chain(L2A_LAYERS.iter())
+                .copied()
+                .collect(),
+        }
+    }
+
+    /// EPSG code of the WGS 84 UTM zone of the tile
+    ///
+    /// Derived from the UTM zone number and the latitude band of the tile number. Bands `N`
+    /// and above are located on the northern hemisphere (`326xx`), the bands below
+    /// on the southern hemisphere (`327xx`).
+    pub fn utm_epsg(&self) -> Option<u32> {
+        let tile = self.mgrs_tile().ok()?;
+        if tile.latitude_band >= 'N' {
+            Some(32600 + tile.utm_zone as u32)
+        } else {
+            Some(32700 + tile.utm_zone as u32)
+        }
+    }
+
+    /// rough estimate of the centre of the tile as `(longitude, latitude)`
+    ///
+    /// The longitude is derived from the central meridian of the UTM zone and the column of
+    /// the 100km square, the latitude is the centre of the latitude band. The latitude may
+    /// therefore be off by up to four degrees, this is only intended for coarse lookups.
+    pub fn approx_centroid(&self) -> Option<(f64, f64)> {
+        let tile = self.mgrs_tile().ok()?;
+        let zone = tile.utm_zone as u32;
+
+        let band_index = MGRS_LATITUDE_BANDS.find(tile.latitude_band)? as f64;
+        let lat = if tile.latitude_band == 'X' {
+            78.0
+        } else {
+            -80.0 + 8.0 * band_index + 4.0
+        };
+
+        // the column letters repeat every three zones
+        let column_letters = ["ABCDEFGH", "JKLMNPQR", "STUVWXYZ"][((zone - 1) % 3) as usize];
+        let column_index = column_letters.find(tile.grid_square.chars().next()?)? as f64;
+        // offset of the centre of the square from the central meridian (easting 500km)
+        let offset_km = (column_index + 1.0) * 100.0 + 50.0 - 500.0;
+        let central_meridian = -183.0 + 6.0 * zone as f64;
+        let lon = central_meridian + offset_km / (111.32 * lat.to_radians().cos());
+
+        Some((lon, lat))
+    }
+
+    /// estimate of the next acquisition of the same tile by the same satellite
+    ///
+    /// Each satellite revisits a tile every 10 days at roughly the same time of day. With
+    /// both satellites combined the revisit is 5 days, but this only considers the
+    /// satellite of this product. This is an estimate, actual acquisitions depend on the
+    /// acquisition plan.
+    pub fn next_expected_acquisition(&self) -> NaiveDateTime {
+        self.start_datetime + Duration::days(SATELLITE_REVISIT_DAYS)
+    }
+
+    /// repeat cycle and absolute orbit number of the product
+    ///
+    /// The satellites complete 143 orbits in a 10 day repeat cycle, with the relative orbit
+    /// being `(absolute - 2) mod 143 + 1` for both satellites. The absolute orbit is
+    /// extrapolated from a reference orbit of the satellite using the sensing start and then
+    /// snapped to the orbit matching the relative orbit of the product, so this is exact as
+    /// long as the orbit is maintained within half a cycle of the nominal one. The cycle is
+    /// counted from 1, starting with the first absolute orbit.
+    ///
+    /// Returns `None` when the sensing start predates the launch of the satellite.
+    pub fn cycle_and_absolute_orbit(&self) -> Option<(u16, u32)> {
+        let (launch, reference_orbit, reference_datetime) = self.mission_id.orbit_reference();
+        if self.start_datetime < launch {
+            return None;
+        }
+        let orbit_seconds = (CYCLE_DAYS * 86400) as f64 / ORBITS_PER_CYCLE as f64;
+        let estimated = reference_orbit as f64
+            + (self.start_datetime - reference_datetime).num_seconds() as f64 / orbit_seconds;
+
+        // relative orbit of the estimate, then move to the closest orbit with the relative
+        // orbit of the product
+        let estimated = estimated.round() as i64;
+        let estimated_relative = (estimated - 2).rem_euclid(ORBITS_PER_CYCLE as i64) + 1;
+        let mut delta = (self.relative_orbit_number as i64 - estimated_relative)
+            .rem_euclid(ORBITS_PER_CYCLE as i64);
+        if delta > ORBITS_PER_CYCLE as i64 / 2 {
+            delta -= ORBITS_PER_CYCLE as i64;
+        }
+        let absolute_orbit = u32::try_from(estimated + delta).ok().filter(|o| *o >= 1)?;
+        let cycle = ((absolute_orbit - 1) / ORBITS_PER_CYCLE + 1) as u16;
+        Some((cycle, absolute_orbit))
+    }
+
+    /// the tile number split into its MGRS components
+    pub fn mgrs_tile(&self) -> Result<MgrsTile, MgrsTileError> {
+        self.tile_number.parse()
+    }
+}
+
+/// revisit time of a single satellite, in days
+const SATELLITE_REVISIT_DAYS: i64 = 10;
+
+/// MGRS latitude bands from south to north
+const MGRS_LATITUDE_BANDS: &str = "CDEFGHJKLMNPQRSTUVWX";
+
+/// letters used for the columns and rows of the MGRS 100km squares
+const MGRS_SQUARE_LETTERS: &str = "ABCDEFGHJKLMNPQRSTUVWXYZ";
+
+/// components of a MGRS tile number like `53NMJ`
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MgrsTile {
+    /// UTM zone (1 - 60)
+    pub utm_zone: u8,
+
+    /// latitude band (`C` - `X`, excluding `I` and `O`)
+    pub latitude_band: char,
+
+    /// column and row letters of the 100km square
+    pub grid_square: String,
+}
+
+/// error for malformed MGRS tile numbers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MgrsTileError {
+    InvalidUtmZone,
+    InvalidLatitudeBand,
+    InvalidGridSquare,
+}
+
+impl fmt::Display for MgrsTileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MgrsTileError::InvalidUtmZone => "invalid UTM zone",
+            MgrsTileError::InvalidLatitudeBand => "invalid latitude band",
+            MgrsTileError::InvalidGridSquare => "invalid 100km grid square",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MgrsTileError {}
+
+impl FromStr for MgrsTile {
+    type Err = MgrsTileError;
+
+    /// split the leading one or two digit UTM zone, the latitude band and the two letter
+    /// 100km square
+    fn from_str(tile_number: &str) -> Result<Self, Self::Err> {
+        let zone_digits = tile_number
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .count();
+        if !(1..=2).contains(&zone_digits) {
+            return Err(MgrsTileError::InvalidUtmZone);
+        }
+        let utm_zone: u8 = tile_number[..zone_digits]
+            .parse()
+            .map_err(|_| MgrsTileError::InvalidUtmZone)?;
+        if !(1..=60).contains(&utm_zone) {
+            return Err(MgrsTileError::InvalidUtmZone);
+        }
+
+        let mut chars = tile_number[zone_digits..].chars();
+        let latitude_band = chars
+            .next()
+            .map(|c| c.to_ascii_uppercase())
+            .filter(|c| MGRS_LATITUDE_BANDS.contains(*c))
+            .ok_or(MgrsTileError::InvalidLatitudeBand)?;
+
+        let grid_square = chars.as_str().to_uppercase();
+        if grid_square.chars().count() != 2
+            || !grid_square.chars().all(|c| MGRS_SQUARE_LETTERS.contains(c))
+        {
+            return Err(MgrsTileError::InvalidGridSquare);
+        }
+
+        Ok(Self {
+            utm_zone,
+            latitude_band,
+            grid_square,
+        })
+    }
+}
+
+impl fmt::Display for MgrsTile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}{}{}",
+            self.utm_zone, self.latitude_band, self.grid_square
+        )
+    }
 }
 
 fn consume_product_sep(s: &str) -> IResult<&str, core::primitive::char> {
@@ -114,6 +458,129 @@ fn parse_tile_number(s: &str) -> IResult<&str, String> {
     Ok((s, tn.to_uppercase()))
 }
 
+/// Sentinel 2 granule
+///
+/// Name of a granule (tile) folder within the `GRANULE` directory of a product
+/// following the compact naming convention, for example `L1C_T53NMJ_A008006_20170105T013443`.
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Granule {
+    /// product level
+    pub product_level: ProductLevel,
+
+    /// tile number
+    pub tile_number: String,
+
+    /// absolute orbit number
+    pub absolute_orbit_number: u32,
+
+    /// sensing start of the datastrip the granule belongs to
+    ///
+    /// The datastrip is a part of the datatake, so this is at or shortly after the
+    /// `start_datetime` of the product. It is not the generation time found in the product
+    /// discriminator of newer products.
+    pub sensing_datetime: NaiveDateTime,
+}
+
+/// nom parser function
+pub fn parse_granule(s: &str) -> IResult<&str, Granule> {
+    let (s, product_level) = parse_product_level(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, tile_number) = parse_tile_number(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, _) = tag_no_case("a")(s)?;
+    let (s, absolute_orbit_number) = take_n_digits_in_range(6, 1..=999999)(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, sensing_datetime) = parse_esa_timestamp(s)?;
+
+    Ok((
+        s,
+        Granule {
+            product_level,
+            tile_number,
+            absolute_orbit_number,
+            sensing_datetime,
+        },
+    ))
+}
+
+/// image file of a single band or layer within the `IMG_DATA` directory of a granule
+///
+/// For example `T53NMJ_20170105T013442_B01.jp2`, L2A products append the resolution
+/// (`T53NMJ_20170105T013442_B02_10m.jp2`). Files holding the data of a single detector carry
+/// its number after the band, as `D01` or `detector01`.
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BandAsset {
+    /// tile number
+    pub tile_number: String,
+
+    /// datatake sensing datetime
+    pub sensing_datetime: NaiveDateTime,
+
+    /// band or layer, for example `B8A` or `TCI`
+    pub band: String,
+
+    /// detector number (1 - 12)
+    pub detector: Option<u8>,
+
+    /// resolution in meters
+    pub resolution: Option<u16>,
+}
+
+impl fmt::Display for BandAsset {
+    /// the name of the file, without the file extension
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "T{}_{}_{}",
+            self.tile_number,
+            format_esa_timestamp(&self.sensing_datetime),
+            self.band
+        )?;
+        if let Some(detector) = self.detector {
+            write!(f, "_D{:02}", detector)?;
+        }
+        if let Some(resolution) = self.resolution {
+            write!(f, "_{}m", resolution)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_detector(s: &str) -> IResult<&str, u8> {
+    let (s, _) = alt((tag_no_case("detector"), tag_no_case("d")))(s)?;
+    take_n_digits_in_range(2, 1..=12)(s)
+}
+
+fn parse_resolution(s: &str) -> IResult<&str, u16> {
+    let (s, resolution) = take_n_digits_in_range(2, 10..=60)(s)?;
+    let (s, _) = tag_no_case("m")(s)?;
+    Ok((s, resolution))
+}
+
+/// nom parser function
+pub fn parse_band_asset(s: &str) -> IResult<&str, BandAsset> {
+    let (s, tile_number) = parse_tile_number(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, sensing_datetime) = parse_esa_timestamp(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, band) = take_alphanumeric_n(3)(s)?;
+    let (s, detector) = opt(preceded(consume_product_sep, parse_detector))(s)?;
+    let (s, resolution) = opt(preceded(consume_product_sep, parse_resolution))(s)?;
+
+    Ok((
+        s,
+        BandAsset {
+            tile_number,
+            sensing_datetime,
+            band: band.to_uppercase(),
+            detector,
+            resolution,
+        },
+    ))
+}
+
 /// nom parser function
 /// parse new format Naming Convention for Sentinel-2 Level-1C products generated after 6 December 2016:
 pub fn parse_product(s: &str) -> IResult<&str, Product> {
@@ -129,8 +596,9 @@ pub fn parse_product(s: &str) -> IResult<&str, Product> {
     let (s, relative_orbit_number) = parse_relative_orbit_number(s)?;
     let (s, _) = consume_product_sep(s)?;
     let (s, tile_number) = parse_tile_number(s)?;
-    let (s, _) = consume_product_sep(s)?;
-    let (s, product_discriminator) = take_alphanumeric_n(15)(s)?;
+    let (s, product_discriminator) =
+        opt(preceded(consume_product_sep, take_alphanumeric_n(15)))(s)?;
+    let (s, container_extension) = parse_container_extension(SAFE_EXTENSION)(s)?;
 
     Ok((
         s,
@@ -141,17 +609,193 @@ pub fn parse_product(s: &str) -> IResult<&str, Product> {
             pdgs_baseline_number,
             relative_orbit_number,
             tile_number,
-            product_discriminator: product_discriminator.to_uppercase(),
+            product_discriminator: product_discriminator.map(|pd| pd.to_uppercase()),
+            container_extension,
+        },
+    ))
+}
+
+/// the date of the sensing start
+impl JulianDate for Product {
+    fn day_of_year(&self) -> u32 {
+        self.start_datetime.date().day_of_year()
+    }
+
+    fn julian_string(&self) -> String {
+        self.start_datetime.date().julian_string()
+    }
+}
+
+impl fmt::Display for Product {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_identifier(f)?;
+        if let Some(container_extension) = &self.container_extension {
+            write!(f, ".{}", container_extension)?;
+        }
+        Ok(())
+    }
+}
+
+/// file class of products named by the legacy long naming convention
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FileClass {
+    /// operational
+    OPER,
+
+    /// test
+    TEST,
+}
+
+impl FileClass {
+    /// code as used in identifiers
+    pub fn code(&self) -> &'static str {
+        match self {
+            FileClass::OPER => "OPER",
+            FileClass::TEST => "TEST",
+        }
+    }
+}
+
+/// Sentinel 2 product named by the legacy long naming convention
+///
+/// Used for products generated before 6 December 2016, for example
+/// `S2A_OPER_PRD_MSIL1C_PDMC_20160113T172424_R051_V20160113T104035_20160113T104035`.
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LongProduct {
+    /// mission id
+    pub mission_id: MissionId,
+
+    /// file class
+    pub file_class: FileClass,
+
+    /// product level
+    pub product_level: ProductLevel,
+
+    /// PDGS site centre which generated the product, for example `PDMC`
+    pub site_centre: String,
+
+    /// processing datetime
+    pub processing_datetime: NaiveDateTime,
+
+    /// Relative Orbit number (R001 - R143)
+    pub relative_orbit_number: u8,
+
+    /// start of the applicability time period, the sensing start
+    pub validity_start_datetime: NaiveDateTime,
+
+    /// end of the applicability time period, the sensing stop
+    pub validity_stop_datetime: NaiveDateTime,
+
+    /// extension of the `.SAFE` directory the product is distributed in, without the dot
+    pub container_extension: Option<String>,
+}
+
+impl fmt::Display for LongProduct {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}_{}_PRD_MSI{}_{}_{}_R{:03}_V{}_{}",
+            self.mission_id.code(),
+            self.file_class.code(),
+            self.product_level.code(),
+            self.site_centre,
+            format_esa_timestamp(&self.processing_datetime),
+            self.relative_orbit_number,
+            format_esa_timestamp(&self.validity_start_datetime),
+            format_esa_timestamp(&self.validity_stop_datetime),
+        )?;
+        if let Some(container_extension) = &self.container_extension {
+            write!(f, ".{}", container_extension)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_file_class(s: &str) -> IResult<&str, FileClass> {
+    alt((
+        map(tag_no_case("oper"), |_| FileClass::OPER),
+        map(tag_no_case("test"), |_| FileClass::TEST),
+    ))(s)
+}
+
+/// nom parser function
+/// parse the legacy long naming convention of products generated before 6 December 2016
+pub fn parse_product_long(s: &str) -> IResult<&str, LongProduct> {
+    let (s, mission_id) = parse_mission_id(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, file_class) = parse_file_class(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, _) = tag_no_case("prd")(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, _) = tag_no_case("msi")(s)?;
+    let (s, product_level) = parse_product_level(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, site_centre) = take_alphanumeric_n(4)(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, processing_datetime) = parse_esa_timestamp(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, relative_orbit_number) = parse_relative_orbit_number(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, _) = tag_no_case("v")(s)?;
+    let (s, validity_start_datetime) = parse_esa_timestamp(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, validity_stop_datetime) = parse_esa_timestamp(s)?;
+    let (s, container_extension) = parse_container_extension(SAFE_EXTENSION)(s)?;
+
+    Ok((
+        s,
+        LongProduct {
+            mission_id,
+            file_class,
+            product_level,
+            site_centre: site_centre.to_uppercase(),
+            processing_datetime,
+            relative_orbit_number,
+            validity_start_datetime,
+            validity_stop_datetime,
+            container_extension,
         },
     ))
 }
 
+/// any of the Sentinel 2 name forms supported by this module
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SubIdentifier {
+    Product(Product),
+    Granule(Granule),
+    BandAsset(BandAsset),
+
+    /// tile number, given as `T53NMJ`
+    Tile(String),
+}
+
+/// nom parser function for all name forms
+pub fn parse_any(s: &str) -> IResult<&str, SubIdentifier> {
+    alt((
+        map(parse_product, SubIdentifier::Product),
+        map(parse_granule, SubIdentifier::Granule),
+        map(parse_band_asset, SubIdentifier::BandAsset),
+        map(parse_tile_number, SubIdentifier::Tile),
+    ))(s)
+}
+
 impl_from_str!(parse_product, Product);
+impl_from_str!(parse_product_long, LongProduct);
+impl_from_str!(parse_granule, Granule);
+impl_from_str!(parse_band_asset, BandAsset);
+impl_from_str!(parse_any, SubIdentifier);
 
 #[cfg(test)]
 mod tests {
-    use crate::identifiers::sentinel2::{parse_product, MissionId, Product, ProductLevel};
-    use crate::identifiers::tests::apply_to_samples_from_txt;
+    use crate::identifiers::sentinel2::{
+        parse_band_asset, parse_granule, parse_product, parse_product_long, BandAsset, FileClass,
+        MgrsTile, MgrsTileError, MissionId, Product, ProductLevel, SubIdentifier,
+    };
+    use crate::identifiers::tests::{apply_to_samples_from_txt, read_samples_from_txt};
+    use crate::{Name, NameLong};
     use std::str::FromStr;
 
     #[test]
@@ -165,7 +809,138 @@ mod tests {
         assert_eq!(product.pdgs_baseline_number, (2, 4));
         assert_eq!(product.relative_orbit_number, 31);
         assert_eq!(product.tile_number.as_str(), "53NMJ");
-        assert_eq!(product.product_discriminator.as_str(), "20170105T013443");
+        assert_eq!(
+            product.product_discriminator.as_deref(),
+            Some("20170105T013443")
+        );
+        assert_eq!(product.container_extension.as_deref(), Some("SAFE"));
+    }
+
+    #[test]
+    fn parse_s2_product_container_extension() {
+        let name = "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443";
+        let (_, product) = parse_product(name).unwrap();
+        assert_eq!(product.container_extension, None);
+        assert_eq!(product.to_string(), name);
+
+        let s = format!("{}.safe", name);
+        let (rest, product) = parse_product(&s).unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(product.container_extension.as_deref(), Some("SAFE"));
+        assert_eq!(product.to_string(), format!("{}.SAFE", name));
+
+        // the extension of Sentinel 3 products is not consumed
+        let s = format!("{}.SEN3", name);
+        let (rest, product) = parse_product(&s).unwrap();
+        assert_eq!(rest, ".SEN3");
+        assert_eq!(product.container_extension, None);
+    }
+
+    #[test]
+    fn product_display_roundtrip() {
+        let (_, product) =
+            parse_product("S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443.SAFE")
+                .unwrap();
+        assert_eq!(
+            product.to_string(),
+            "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443.SAFE"
+        );
+        assert_eq!(
+            product.to_identifier_string(),
+            "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443"
+        );
+
+        let (_, product) = parse_product("S2B_MSIL2A_20170105T013442_N0400_R001_T53NMJ").unwrap();
+        assert_eq!(
+            product.to_string(),
+            "S2B_MSIL2A_20170105T013442_N0400_R001_T53NMJ"
+        );
+
+        apply_to_samples_from_txt("sentinel2_products.txt", |s| {
+            let (_, product) = parse_product(s).unwrap();
+            assert_eq!(product.to_string(), s.to_uppercase());
+        })
+    }
+
+    #[test]
+    fn cycle_and_absolute_orbit() {
+        // absolute orbits as given by the granule names
+        for line in read_samples_from_txt("sentinel2_product_granules.txt") {
+            let (product_name, granule_name) = line.split_once(' ').unwrap();
+            let (_, product) = parse_product(product_name).unwrap();
+            let (_, granule) = parse_granule(granule_name).unwrap();
+
+            let (cycle, absolute_orbit) = product.cycle_and_absolute_orbit().unwrap();
+            assert_eq!(
+                absolute_orbit, granule.absolute_orbit_number,
+                "{}",
+                product_name
+            );
+            assert_eq!(cycle as u32, (absolute_orbit - 1) / 143 + 1);
+        }
+
+        // before the launch of S2B
+        let (_, product) = parse_product("S2B_MSIL1C_20170105T013442_N0204_R031_T53NMJ").unwrap();
+        assert_eq!(product.cycle_and_absolute_orbit(), None);
+    }
+
+    #[test]
+    fn mgrs_tile() {
+        assert_eq!(
+            MgrsTile::from_str("53NMJ").unwrap(),
+            MgrsTile {
+                utm_zone: 53,
+                latitude_band: 'N',
+                grid_square: "MJ".to_string()
+            }
+        );
+        let tile = MgrsTile::from_str("01CDM").unwrap();
+        assert_eq!(tile.utm_zone, 1);
+        assert_eq!(tile.latitude_band, 'C');
+        assert_eq!(tile.grid_square.as_str(), "DM");
+        assert_eq!(tile.to_string(), "01CDM");
+
+        let (_, product) =
+            parse_product("S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443").unwrap();
+        assert_eq!(product.mgrs_tile().unwrap().to_string(), "53NMJ");
+    }
+
+    #[test]
+    fn mgrs_tile_rejects_malformed() {
+        assert_eq!(
+            MgrsTile::from_str("61NMJ"),
+            Err(MgrsTileError::InvalidUtmZone)
+        );
+        assert_eq!(
+            MgrsTile::from_str("NMJ"),
+            Err(MgrsTileError::InvalidUtmZone)
+        );
+        assert_eq!(
+            MgrsTile::from_str("53OMJ"),
+            Err(MgrsTileError::InvalidLatitudeBand)
+        );
+        assert_eq!(
+            MgrsTile::from_str("53NMI"),
+            Err(MgrsTileError::InvalidGridSquare)
+        );
+        assert_eq!(
+            MgrsTile::from_str("53NM"),
+            Err(MgrsTileError::InvalidGridSquare)
+        );
+    }
+
+    #[test]
+    fn utm_epsg_north() {
+        let (_, product) =
+            parse_product("S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443").unwrap();
+        assert_eq!(product.utm_epsg(), Some(32653));
+    }
+
+    #[test]
+    fn utm_epsg_south() {
+        let (_, product) =
+            parse_product("S2B_MSIL2A_20221103T135709_N0400_R067_T21JUL_20221103T155935").unwrap();
+        assert_eq!(product.utm_epsg(), Some(32721));
     }
 
     #[test]
@@ -175,6 +950,197 @@ mod tests {
         })
     }
 
+    #[test]
+    fn parse_s2_product_without_discriminator() {
+        let (_, product) = parse_product("S2A_MSIL2A_20170105T013442_N0204_R031_T53NMJ").unwrap();
+        assert_eq!(product.tile_number.as_str(), "53NMJ");
+        assert_eq!(product.product_discriminator, None);
+    }
+
+    #[test]
+    fn approx_centroid() {
+        let (_, product) =
+            parse_product("S2A_MSIL1C_20150803T101016_N0204_R022_T33UUP_20150803T101010").unwrap();
+        let (lon, lat) = product.approx_centroid().unwrap();
+        assert!((lon - 12.8).abs() < 1.0);
+        assert!((lat - 52.7).abs() < 4.0);
+
+        let (_, product) =
+            parse_product("S2B_MSIL2A_20221103T135709_N0400_R067_T21JUL_20221103T155935").unwrap();
+        let (lon, lat) = product.approx_centroid().unwrap();
+        assert!((lon - -58.4).abs() < 1.0);
+        assert!((lat - -29.3).abs() < 4.0);
+    }
+
+    #[test]
+    fn next_expected_acquisition() {
+        let (_, product) =
+            parse_product("S2A_MSIL1C_20150803T101016_N0204_R022_T33UUP_20150803T101010").unwrap();
+        let (_, next_product) =
+            parse_product("S2A_MSIL1C_20150813T101026_N0204_R022_T33UUP_20150813T101657").unwrap();
+        let delta = next_product.start_datetime - product.next_expected_acquisition();
+        assert!(delta.num_minutes().abs() < 5);
+        assert_eq!(
+            (product.next_expected_acquisition() - product.start_datetime).num_days(),
+            10
+        );
+    }
+
+    #[test]
+    fn product_bands() {
+        let (_, product) =
+            parse_product("S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443").unwrap();
+        assert!(product.bands().contains(&"B10"));
+        assert!(!product.bands().contains(&"SCL"));
+
+        let (_, product) =
+            parse_product("S2B_MSIL2A_20221103T135709_N0400_R067_T21JUL_20221103T155935").unwrap();
+        assert!(!product.bands().contains(&"B10"));
+        assert!(product.bands().contains(&"SCL"));
+        assert!(product.bands().contains(&"AOT"));
+        assert!(product.bands().contains(&"WVP"));
+    }
+
+    #[test]
+    fn parse_s2_long_product() {
+        let (_, product) = parse_product_long(
+            "S2A_OPER_PRD_MSIL1C_PDMC_20160113T172424_R051_V20160113T104035_20160113T104035.SAFE",
+        )
+        .unwrap();
+        assert_eq!(product.mission_id, MissionId::S2A);
+        assert_eq!(product.file_class, FileClass::OPER);
+        assert_eq!(product.product_level, ProductLevel::L1C);
+        assert_eq!(product.site_centre.as_str(), "PDMC");
+        assert_eq!(product.relative_orbit_number, 51);
+        assert_eq!(
+            product
+                .validity_start_datetime
+                .format("%Y%m%dT%H%M%S")
+                .to_string(),
+            "20160113T104035"
+        );
+        assert_eq!(
+            product
+                .processing_datetime
+                .format("%Y%m%dT%H%M%S")
+                .to_string(),
+            "20160113T172424"
+        );
+
+        // the compact parser rejects the long names and vice versa
+        assert!(parse_product(
+            "S2A_OPER_PRD_MSIL1C_PDMC_20160113T172424_R051_V20160113T104035_20160113T104035"
+        )
+        .is_err());
+        assert!(
+            parse_product_long("S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn apply_to_long_product_testdata() {
+        apply_to_samples_from_txt("sentinel2_long_products.txt", |s| {
+            let (_, product) = parse_product_long(s).unwrap();
+            assert_eq!(product.to_string(), s);
+        })
+    }
+
+    #[test]
+    fn parse_s2_granule() {
+        let (_, granule) = parse_granule("L1C_T53NMJ_A008006_20170105T013443").unwrap();
+        assert_eq!(granule.product_level, ProductLevel::L1C);
+        assert_eq!(granule.tile_number.as_str(), "53NMJ");
+        assert_eq!(granule.absolute_orbit_number, 8006);
+    }
+
+    #[test]
+    fn mission_and_level_name() {
+        assert_eq!(MissionId::S2A.name(), "S2A");
+        assert_eq!(MissionId::S2A.name_long(), "Sentinel-2A");
+        assert_eq!(ProductLevel::L1C.name(), "L1C");
+        assert_eq!(
+            ProductLevel::L1C.name_long(),
+            "Level-1C Top-Of-Atmosphere reflectance"
+        );
+        assert_eq!(
+            ProductLevel::L2A.name_long(),
+            "Level-2A Bottom-Of-Atmosphere reflectance"
+        );
+    }
+
+    #[test]
+    fn parse_s2_band_asset() {
+        let (rest, asset) = parse_band_asset("T53NMJ_20170105T013442_B01.jp2").unwrap();
+        assert_eq!(rest, ".jp2");
+        assert_eq!(asset.tile_number.as_str(), "53NMJ");
+        assert_eq!(asset.band.as_str(), "B01");
+        assert_eq!(asset.detector, None);
+        assert_eq!(asset.resolution, None);
+
+        let (_, asset) = parse_band_asset("T53NMJ_20170105T013442_B8A_20m.jp2").unwrap();
+        assert_eq!(asset.band.as_str(), "B8A");
+        assert_eq!(asset.resolution, Some(20));
+        assert_eq!(asset.to_string(), "T53NMJ_20170105T013442_B8A_20m");
+    }
+
+    #[test]
+    fn parse_s2_band_asset_detector() {
+        let (_, asset) = parse_band_asset("T53NMJ_20170105T013442_B01_detector01.jp2").unwrap();
+        assert_eq!(asset.band.as_str(), "B01");
+        assert_eq!(asset.detector, Some(1));
+        assert_eq!(asset.to_string(), "T53NMJ_20170105T013442_B01_D01");
+
+        let (_, asset) = parse_band_asset("T53NMJ_20170105T013442_B12_D12_20m.jp2").unwrap();
+        assert_eq!(asset.detector, Some(12));
+        assert_eq!(asset.resolution, Some(20));
+        assert_eq!(BandAsset::from_str(&asset.to_string()).unwrap(), asset);
+
+        // the MSI has 12 detectors
+        let (rest, asset) = parse_band_asset("T53NMJ_20170105T013442_B01_D13.jp2").unwrap();
+        assert_eq!(asset.detector, None);
+        assert_eq!(rest, "_D13.jp2");
+    }
+
+    #[test]
+    fn sub_identifier_routing() {
+        assert!(matches!(
+            SubIdentifier::from_str("S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443")
+                .unwrap(),
+            SubIdentifier::Product(_)
+        ));
+        assert!(matches!(
+            SubIdentifier::from_str("L1C_T53NMJ_A008006_20170105T013443").unwrap(),
+            SubIdentifier::Granule(_)
+        ));
+        assert!(matches!(
+            SubIdentifier::from_str("T53NMJ_20170105T013442_B01_D01").unwrap(),
+            SubIdentifier::BandAsset(_)
+        ));
+        assert_eq!(
+            SubIdentifier::from_str("t53nmj").unwrap(),
+            SubIdentifier::Tile("53NMJ".to_string())
+        );
+        assert!(SubIdentifier::from_str("X53NMJ").is_err());
+    }
+
+    #[test]
+    fn product_and_granule_agree() {
+        for line in read_samples_from_txt("sentinel2_product_granules.txt") {
+            let (product_name, granule_name) = line.split_once(' ').unwrap();
+            let (_, product) = parse_product(product_name).unwrap();
+            let (_, granule) = parse_granule(granule_name).unwrap();
+
+            assert_eq!(product.tile_number, granule.tile_number);
+            assert_eq!(product.product_level, granule.product_level);
+            // the datastrip is sensed within the datatake, well within one orbit
+            assert!(granule.sensing_datetime >= product.start_datetime);
+            assert!(
+                granule.sensing_datetime - product.start_datetime < chrono::Duration::minutes(100)
+            );
+        }
+    }
+
     #[test]
     fn test_from_str() {
         assert!(
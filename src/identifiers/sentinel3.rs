@@ -15,14 +15,21 @@
 //! ```
 
 use crate::common_parsers::{
-    is_char_alphanumeric, parse_esa_timestamp, take_alphanumeric_n, take_n_digits,
+    format_esa_timestamp, is_char_alphanumeric, parse_container_extension, parse_esa_timestamp,
+    take_n_digits, SEN3_EXTENSION,
 };
-use crate::{impl_from_str, Mission};
+use crate::from_str::{impl_serde_via_str, map_parser};
+use crate::{impl_from_str, Capabilities, Mission, Name, NameLong, ParseError};
+use alloc::format;
+use alloc::string::String;
 use chrono::NaiveDateTime;
+use core::fmt;
+use core::str::FromStr;
 use nom::branch::alt;
 use nom::bytes::complete::{tag_no_case, take, take_while_m_n};
 use nom::character::complete::char;
-use nom::combinator::map;
+use nom::combinator::{cut, map};
+use nom::error::ErrorKind;
 use nom::sequence::tuple;
 use nom::IResult;
 #[cfg(feature = "serde")]
@@ -36,6 +43,34 @@ pub enum MissionId {
     S3AB,
 }
 
+impl MissionId {
+    /// code as used in identifiers
+    pub fn code(&self) -> &'static str {
+        match self {
+            MissionId::S3A => "S3A",
+            MissionId::S3B => "S3B",
+            MissionId::S3AB => "S3_",
+        }
+    }
+}
+
+impl Name for MissionId {
+    /// the code as used in the product name, `S3_` for products of both satellites
+    fn name(&self) -> &str {
+        self.code()
+    }
+}
+
+impl NameLong for MissionId {
+    fn name_long(&self) -> &str {
+        match self {
+            MissionId::S3A => "Sentinel-3A",
+            MissionId::S3B => "Sentinel-3B",
+            MissionId::S3AB => "Sentinel-3A/B",
+        }
+    }
+}
+
 impl From<MissionId> for Mission {
     fn from(_: MissionId) -> Self {
         Mission::Sentinel3
@@ -54,6 +89,85 @@ pub enum DataSource {
     GNSS,
 }
 
+impl DataSource {
+    /// two letter code as used in identifiers
+    pub fn code(&self) -> &'static str {
+        match self {
+            DataSource::OLCI => "OL",
+            DataSource::SLSTR => "SL",
+            DataSource::Synergy => "SY",
+            DataSource::SRAL => "SR",
+            DataSource::DORIS => "DO",
+            DataSource::MWR => "MW",
+            DataSource::GNSS => "GN",
+        }
+    }
+}
+
+impl Name for DataSource {
+    fn name(&self) -> &str {
+        match self {
+            DataSource::OLCI => "OLCI",
+            DataSource::SLSTR => "SLSTR",
+            DataSource::Synergy => "Synergy",
+            DataSource::SRAL => "SRAL",
+            DataSource::DORIS => "DORIS",
+            DataSource::MWR => "MWR",
+            DataSource::GNSS => "GNSS",
+        }
+    }
+}
+
+impl NameLong for DataSource {
+    fn name_long(&self) -> &str {
+        match self {
+            DataSource::OLCI => "Ocean and Land Colour Instrument",
+            DataSource::SLSTR => "Sea and Land Surface Temperature Radiometer",
+            DataSource::Synergy => "Synergy of OLCI and SLSTR",
+            DataSource::SRAL => "SAR Radar Altimeter",
+            DataSource::DORIS => {
+                "Doppler Orbitography and Radiopositioning Integrated by Satellite"
+            }
+            DataSource::MWR => "Microwave Radiometer",
+            DataSource::GNSS => "Global Navigation Satellite System",
+        }
+    }
+}
+
+impl DataSource {
+    /// processing levels products of the data source exist in
+    ///
+    /// Auxiliary data products without a processing level exist for all data sources.
+    pub fn processing_levels(&self) -> &'static [u8] {
+        match self {
+            DataSource::OLCI | DataSource::SLSTR | DataSource::SRAL => &[0, 1, 2],
+            // level 1 only for the MISR misregistration product
+            DataSource::Synergy => &[1, 2],
+            DataSource::MWR => &[0, 1],
+            DataSource::DORIS | DataSource::GNSS => &[0],
+        }
+    }
+
+    /// static facts about the instrument, only available for the optical and thermal
+    /// instruments OLCI and SLSTR
+    pub fn capabilities(&self) -> Option<Capabilities> {
+        match self {
+            DataSource::OLCI => Some(Capabilities {
+                spectral_bands: 21,
+                has_thermal_bands: false,
+                swath_width_km: 1270,
+            }),
+            DataSource::SLSTR => Some(Capabilities {
+                spectral_bands: 11,
+                has_thermal_bands: true,
+                // nadir view
+                swath_width_km: 1420,
+            }),
+            _ => None,
+        }
+    }
+}
+
 #[allow(non_camel_case_types)]
 #[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -105,6 +219,127 @@ pub enum DataType {
     Other(String),
 }
 
+impl Name for DataType {
+    /// the code as used in the product name, without the `_` padding
+    fn name(&self) -> &str {
+        match self {
+            DataType::AER_AX => "AER_AX",
+            DataType::AOD => "AOD",
+            DataType::ATP_AX => "ATP_AX",
+            DataType::CAL => "CAL",
+            DataType::CR0 => "CR0",
+            DataType::CR1 => "CR1",
+            DataType::EFR => "EFR",
+            DataType::EFR_BW => "EFR_BW",
+            DataType::ERR => "ERR",
+            DataType::ERR_BW => "ERR_BW",
+            DataType::FRP => "FRP",
+            DataType::INS_AX => "INS_AX",
+            DataType::LAN => "LAN",
+            DataType::LAP_AX => "LAP_AX",
+            DataType::LFR => "LFR",
+            DataType::LFR_BW => "LFR_BW",
+            DataType::LRR => "LRR",
+            DataType::LRR_BW => "LRR_BW",
+            DataType::LST => "LST",
+            DataType::LST_BW => "LST_BW",
+            DataType::LVI_AX => "LVI_AX",
+            DataType::MSIR => "MSIR",
+            DataType::RAC => "RAC",
+            DataType::RBT => "RBT",
+            DataType::RBT_BW => "RBT_BW",
+            DataType::SLT => "SLT",
+            DataType::SPC => "SPC",
+            DataType::SRA => "SRA",
+            DataType::SYN => "SYN",
+            DataType::SYN_BW => "SYN_BW",
+            DataType::V10 => "V10",
+            DataType::V10_BW => "V10_BW",
+            DataType::VG1 => "VG1",
+            DataType::VG1_BW => "VG1_BW",
+            DataType::VGP => "VGP",
+            DataType::VGP_BW => "VGP_BW",
+            DataType::WAT => "WAT",
+            DataType::WCT => "WCT",
+            DataType::WFR => "WFR",
+            DataType::WFR_BW => "WFR_BW",
+            DataType::WRR => "WRR",
+            DataType::WRR_BW => "WRR_BW",
+            DataType::WST => "WST",
+            DataType::WST_BW => "WST_BW",
+            DataType::Other(s) => s.as_str(),
+        }
+    }
+}
+
+impl NameLong for DataType {
+    fn name_long(&self) -> &str {
+        match self {
+            DataType::AER_AX => "Aerosol auxiliary data",
+            DataType::AOD => "Aerosol Optical Depth",
+            DataType::ATP_AX => "ATP auxiliary data",
+            DataType::CAL => "Calibration",
+            DataType::CR0 => "CR0 calibration",
+            DataType::CR1 => "CR1 calibration",
+            DataType::EFR => "Full Resolution Top of Atmosphere Radiance",
+            DataType::EFR_BW => "Full Resolution Top of Atmosphere Radiance browse",
+            DataType::ERR => "Reduced Resolution Top of Atmosphere Radiance",
+            DataType::ERR_BW => "Reduced Resolution Top of Atmosphere Radiance browse",
+            DataType::FRP => "Fire Radiative Power",
+            DataType::INS_AX => "Instrument auxiliary data",
+            DataType::LAN => "Land altimetry",
+            DataType::LAP_AX => "LAP auxiliary data",
+            DataType::LFR => "Land Full Resolution",
+            DataType::LFR_BW => "Land Full Resolution browse",
+            DataType::LRR => "Land Reduced Resolution",
+            DataType::LRR_BW => "Land Reduced Resolution browse",
+            DataType::LST => "Land Surface Temperature",
+            DataType::LST_BW => "Land Surface Temperature browse",
+            DataType::LVI_AX => "LVI auxiliary data",
+            DataType::MSIR => "Misregistration",
+            DataType::RAC => "Radiometric Calibration",
+            DataType::RBT => "Radiance and Brightness Temperature",
+            DataType::RBT_BW => "Radiance and Brightness Temperature browse",
+            DataType::SLT => "SLSTR instrument data",
+            DataType::SPC => "Spectral Calibration",
+            DataType::SRA => "SRAL altimetry",
+            DataType::SYN => "Synergy Surface Reflectance and Aerosol",
+            DataType::SYN_BW => "Synergy Surface Reflectance and Aerosol browse",
+            DataType::V10 => "10-day VEGETATION-like Synthesis",
+            DataType::V10_BW => "10-day VEGETATION-like Synthesis browse",
+            DataType::VG1 => "1-day VEGETATION-like Synthesis",
+            DataType::VG1_BW => "1-day VEGETATION-like Synthesis browse",
+            DataType::VGP => "VEGETATION-like Top of Atmosphere Reflectance",
+            DataType::VGP_BW => "VEGETATION-like Top of Atmosphere Reflectance browse",
+            DataType::WAT => "Water altimetry",
+            DataType::WCT => "Water Combined Temperature",
+            DataType::WFR => "Water Full Resolution",
+            DataType::WFR_BW => "Water Full Resolution browse",
+            DataType::WRR => "Water Reduced Resolution",
+            DataType::WRR_BW => "Water Reduced Resolution browse",
+            DataType::WST => "Water Surface Temperature",
+            DataType::WST_BW => "Water Surface Temperature browse",
+            DataType::Other(s) => s.as_str(),
+        }
+    }
+}
+
+impl FromStr for DataType {
+    type Err = ParseError;
+
+    /// parse the data type code, the code may be given with or without the `_` padding
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::NotEnoughData(0));
+        }
+        // codes are at most 6 characters long, padding must not hide anything after them
+        if let Some((pos, _)) = s.char_indices().nth(6) {
+            return Err(ParseError::failed_at(s, pos));
+        }
+        map_parser(parse_data_type)(&format!("{:_<6}", s))
+    }
+}
+
 #[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum InstanceId {
@@ -126,6 +361,36 @@ pub enum InstanceId {
     Aux,
 }
 
+impl fmt::Display for InstanceId {
+    /// the 17 character instance id, padded with `_`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InstanceId::Stripe {
+                duration,
+                cycle_number,
+                relative_order_number,
+            } => write!(
+                f,
+                "{:04}_{:03}_{:03}_____",
+                duration, cycle_number, relative_order_number
+            ),
+            InstanceId::Frame {
+                duration,
+                cycle_number,
+                relative_order_number,
+                frame_along_track_coordinate,
+            } => write!(
+                f,
+                "{:04}_{:03}_{:03}_{:04}",
+                duration, cycle_number, relative_order_number, frame_along_track_coordinate
+            ),
+            InstanceId::GlobalTile => f.write_str("GLOBAL___________"),
+            InstanceId::Tile { tile_identifier } => write!(f, "{:_<17}", tile_identifier),
+            InstanceId::Aux => f.write_str("_________________"),
+        }
+    }
+}
+
 /// Sentinel 3 product
 #[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -141,18 +406,123 @@ pub struct Product {
 
     pub start_datetime: NaiveDateTime,
     pub stop_datetime: NaiveDateTime,
+
+    /// creation datetime of the product, never before the `stop_datetime`
     pub product_creation_datetime: NaiveDateTime,
     pub instance_id: InstanceId,
+
+    /// centre generating the file
+    ///
+    /// Always three characters, shorter centre codes keep their `_` padding.
     pub centre_generating_file: String,
     pub platform: Option<Platform>,
     pub timeliness: Option<Timeliness>,
 
     /// baseline collection or data usage
     pub collection_or_usage: Option<String>,
+
+    /// extension of the `.SEN3` directory the product is distributed in, without the dot
+    pub container_extension: Option<String>,
+}
+
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ResolutionClass {
+    Full,
+    Reduced,
 }
 
+/// thematic domain of a geophysical product
 #[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Copy, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Domain {
+    Land,
+    Water,
+    Atmosphere,
+    Fire,
+}
+
+impl DataType {
+    /// thematic domain, only for the level 2 geophysical products
+    pub fn domain(&self) -> Option<Domain> {
+        match self {
+            DataType::FRP => Some(Domain::Fire),
+            DataType::LFR
+            | DataType::LFR_BW
+            | DataType::LRR
+            | DataType::LRR_BW
+            | DataType::LST
+            | DataType::LST_BW
+            | DataType::LAN
+            | DataType::SYN
+            | DataType::SYN_BW
+            | DataType::V10
+            | DataType::V10_BW
+            | DataType::VG1
+            | DataType::VG1_BW
+            | DataType::VGP
+            | DataType::VGP_BW => Some(Domain::Land),
+            DataType::WFR
+            | DataType::WFR_BW
+            | DataType::WRR
+            | DataType::WRR_BW
+            | DataType::WST
+            | DataType::WST_BW
+            | DataType::WAT => Some(Domain::Water),
+            DataType::AOD => Some(Domain::Atmosphere),
+            _ => None,
+        }
+    }
+}
+
+impl Product {
+    /// full or reduced resolution, for the data types which distinguish these
+    pub fn resolution_class(&self) -> Option<ResolutionClass> {
+        match self.data_type {
+            DataType::EFR
+            | DataType::EFR_BW
+            | DataType::LFR
+            | DataType::LFR_BW
+            | DataType::WFR
+            | DataType::WFR_BW => Some(ResolutionClass::Full),
+            DataType::ERR
+            | DataType::ERR_BW
+            | DataType::LRR
+            | DataType::LRR_BW
+            | DataType::WRR
+            | DataType::WRR_BW => Some(ResolutionClass::Reduced),
+            _ => None,
+        }
+    }
+
+    /// nominal number of pixels across track of the OLCI full and reduced resolution products
+    ///
+    /// Reference data for sizing arrays, the number of pixels of a specific product
+    /// may slightly differ.
+    pub fn nominal_pixel_count_across_track(&self) -> Option<u32> {
+        if self.data_source != DataSource::OLCI {
+            return None;
+        }
+        self.resolution_class().map(|rc| match rc {
+            ResolutionClass::Full => 4865,
+            ResolutionClass::Reduced => 1217,
+        })
+    }
+
+    /// compositing period in days of the VEGETATION-like synthesis products
+    ///
+    /// `V10` products are 10-day composites, `VG1` products 1-day composites.
+    pub fn compositing_period_days(&self) -> Option<u8> {
+        match self.data_type {
+            DataType::V10 | DataType::V10_BW => Some(10),
+            DataType::VG1 | DataType::VG1_BW => Some(1),
+            _ => None,
+        }
+    }
+}
+
+/// serialized as the single letter code used in the product name
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Copy, Hash)]
 pub enum Platform {
     Operational,
     Reference,
@@ -160,14 +530,123 @@ pub enum Platform {
     Reprocessing,
 }
 
+impl Platform {
+    /// single letter code of the platform as used in the product name
+    pub fn code(&self) -> char {
+        match self {
+            Platform::Operational => 'O',
+            Platform::Reference => 'F',
+            Platform::Development => 'D',
+            Platform::Reprocessing => 'R',
+        }
+    }
+}
+
+impl Name for Platform {
+    fn name(&self) -> &str {
+        match self {
+            Platform::Operational => "O",
+            Platform::Reference => "F",
+            Platform::Development => "D",
+            Platform::Reprocessing => "R",
+        }
+    }
+}
+
+impl NameLong for Platform {
+    fn name_long(&self) -> &str {
+        match self {
+            Platform::Operational => "Operational",
+            Platform::Reference => "Reference",
+            Platform::Development => "Development",
+            Platform::Reprocessing => "Reprocessing",
+        }
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl FromStr for Platform {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        map_parser(parse_platform)(s)?.ok_or_else(|| ParseError::failed_at(s, 0))
+    }
+}
+
+impl_serde_via_str!(Platform);
+
+/// single letter code of an optional platform, `_` when the platform is not set
+pub fn platform_code(platform: Option<Platform>) -> char {
+    platform.map(|p| p.code()).unwrap_or('_')
+}
+
+/// serialized as the two letter code used in the product name
 #[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Copy, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Timeliness {
     NRT,
     STC,
     NTC,
 }
 
+impl Timeliness {
+    /// two letter code of the timeliness as used in the product name
+    pub fn code(&self) -> &'static str {
+        match self {
+            Timeliness::NRT => "NR",
+            Timeliness::STC => "ST",
+            Timeliness::NTC => "NT",
+        }
+    }
+
+    /// rank of the timeliness by data refinement, higher is better
+    ///
+    /// Non-Time-Critical ranks above Short-Time-Critical, which ranks above Near-Real-Time.
+    pub fn quality_rank(&self) -> u8 {
+        match self {
+            Timeliness::NRT => 1,
+            Timeliness::STC => 2,
+            Timeliness::NTC => 3,
+        }
+    }
+}
+
+impl Name for Timeliness {
+    fn name(&self) -> &str {
+        self.code()
+    }
+}
+
+impl NameLong for Timeliness {
+    fn name_long(&self) -> &str {
+        match self {
+            Timeliness::NRT => "Near Real Time",
+            Timeliness::STC => "Short Time Critical",
+            Timeliness::NTC => "Non Time Critical",
+        }
+    }
+}
+
+impl fmt::Display for Timeliness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for Timeliness {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        map_parser(parse_timeliness)(s)?.ok_or_else(|| ParseError::failed_at(s, 0))
+    }
+}
+
+impl_serde_via_str!(Timeliness);
+
 fn consume_product_sep(s: &str) -> IResult<&str, core::primitive::char> {
     char('_')(s)
 }
@@ -252,54 +731,51 @@ fn parse_data_type(s: &str) -> IResult<&str, DataType> {
     ))(s)
 }
 
-fn parse_instance(s: &str) -> IResult<&str, InstanceId> {
-    alt((
-        map(take_while_m_n(17, 17, |c| c == '_'), |_| InstanceId::Aux),
-        map(tag_no_case("GLOBAL___________"), |_| InstanceId::GlobalTile),
+/// stripe (`0179_088_157_____`) or frame (`0179_088_157_1800`) instance
+///
+/// Both share the same numeric layout and only differ in the last four characters. Once
+/// the numeric layout matched, these must be either digits or underscores - anything else
+/// is an error instead of falling back to the other instance types.
+fn parse_stripe_or_frame(s: &str) -> IResult<&str, InstanceId> {
+    let (s, (duration, _, cycle_number, _, relative_order_number, _)) = tuple((
+        take_n_digits::<u32>(4),
+        consume_product_sep,
+        take_n_digits::<u32>(3),
+        consume_product_sep,
+        take_n_digits::<u32>(3),
+        consume_product_sep,
+    ))(s)?;
+    cut(alt((
         map(
-            tuple((
-                take_n_digits::<u32>(4),
-                consume_product_sep,
-                take_n_digits::<u32>(3),
-                consume_product_sep,
-                take_n_digits::<u32>(3),
-                consume_product_sep,
-                take_while_m_n(4, 4, |c| c == '_'),
-            )),
-            |(duration, _, cycle_number, _, relative_order_number, _, _)| InstanceId::Stripe {
+            take_n_digits::<u32>(4),
+            move |frame_along_track_coordinate| InstanceId::Frame {
                 duration,
                 cycle_number,
                 relative_order_number,
+                frame_along_track_coordinate,
             },
         ),
-        map(
-            tuple((
-                take_n_digits::<u32>(4),
-                consume_product_sep,
-                take_n_digits::<u32>(3),
-                consume_product_sep,
-                take_n_digits::<u32>(3),
-                consume_product_sep,
-                take_n_digits::<u32>(4),
-            )),
-            |(
-                duration,
-                _,
-                cycle_number,
-                _,
-                relative_order_number,
-                _,
-                frame_along_track_coordinate,
-            )| InstanceId::Frame {
+        map(take_while_m_n(4, 4, |c| c == '_'), move |_| {
+            InstanceId::Stripe {
                 duration,
                 cycle_number,
                 relative_order_number,
-                frame_along_track_coordinate,
+            }
+        }),
+    )))(s)
+}
+
+fn parse_instance(s: &str) -> IResult<&str, InstanceId> {
+    alt((
+        map(take_while_m_n(17, 17, |c| c == '_'), |_| InstanceId::Aux),
+        map(tag_no_case("GLOBAL___________"), |_| InstanceId::GlobalTile),
+        parse_stripe_or_frame,
+        map(
+            take_while_m_n(17, 17, |c| is_char_alphanumeric(c) || c == '_'),
+            |ti: &str| InstanceId::Tile {
+                tile_identifier: ti.trim_end_matches('_').to_uppercase(),
             },
         ),
-        map(take_alphanumeric_n(17), |ti| InstanceId::Tile {
-            tile_identifier: ti.to_uppercase(),
-        }),
     ))(s)
 }
 
@@ -328,10 +804,19 @@ pub fn parse_product(s: &str) -> IResult<&str, Product> {
     let (s, _) = consume_product_sep(s)?;
     let (s, data_source) = parse_data_source(s)?;
     let (s, _) = consume_product_sep(s)?;
+    let s_processing_level = s;
     let (s, processing_level) = alt((
         map(take_n_digits::<u8>(1), Some),
         map(consume_product_sep, |_| None),
     ))(s)?;
+    if let Some(level) = processing_level {
+        if !data_source.processing_levels().contains(&level) {
+            return Err(nom::Err::Error(nom::error::Error::new(
+                s_processing_level,
+                ErrorKind::Verify,
+            )));
+        }
+    }
     let (s, _) = consume_product_sep(s)?;
     let (s, data_type) = parse_data_type(s)?;
     let (s, _) = consume_product_sep(s)?;
@@ -339,11 +824,22 @@ pub fn parse_product(s: &str) -> IResult<&str, Product> {
     let (s, _) = consume_product_sep(s)?;
     let (s, stop_datetime) = parse_esa_timestamp(s)?;
     let (s, _) = consume_product_sep(s)?;
+    let s_creation = s;
     let (s, product_creation_datetime) = parse_esa_timestamp(s)?;
+    if product_creation_datetime < stop_datetime {
+        // a product can not be created before the end of its sensing period
+        return Err(nom::Err::Error(nom::error::Error::new(
+            s_creation,
+            ErrorKind::Verify,
+        )));
+    }
     let (s, _) = consume_product_sep(s)?;
     let (s, instance_id) = parse_instance(s)?;
     let (s, _) = consume_product_sep(s)?;
-    let (s, centre_generating_file) = map(take_alphanumeric_n(3), |v| v.to_uppercase())(s)?;
+    let (s, centre_generating_file) = map(
+        take_while_m_n(3, 3, |c| is_char_alphanumeric(c) || c == '_'),
+        |v: &str| v.to_uppercase(),
+    )(s)?;
     let (s, _) = consume_product_sep(s)?;
     let (s, platform) = parse_platform(s)?;
     let (s, _) = consume_product_sep(s)?;
@@ -355,6 +851,7 @@ pub fn parse_product(s: &str) -> IResult<&str, Product> {
         }),
         map(take_while_m_n(3, 3, |c| c == '_'), |_| None),
     ))(s)?;
+    let (s, container_extension) = parse_container_extension(SEN3_EXTENSION)(s)?;
 
     Ok((
         s,
@@ -371,16 +868,399 @@ pub fn parse_product(s: &str) -> IResult<&str, Product> {
             platform,
             timeliness,
             collection_or_usage,
+            container_extension,
         },
     ))
 }
 
+impl fmt::Display for Product {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}_{}_{}_{:_<6}_{}_{}_{}_{}_{}_{}_{}_{:_<3}",
+            self.mission_id.code(),
+            self.data_source.code(),
+            self.processing_level
+                .map(|l| char::from(b'0' + l))
+                .unwrap_or('_'),
+            self.data_type.name(),
+            format_esa_timestamp(&self.start_datetime),
+            format_esa_timestamp(&self.stop_datetime),
+            format_esa_timestamp(&self.product_creation_datetime),
+            self.instance_id,
+            self.centre_generating_file,
+            platform_code(self.platform),
+            self.timeliness.map(|t| t.code()).unwrap_or("__"),
+            self.collection_or_usage.as_deref().unwrap_or_default()
+        )?;
+        if let Some(container_extension) = &self.container_extension {
+            write!(f, ".{}", container_extension)?;
+        }
+        Ok(())
+    }
+}
+
+/// select the best product from products sharing the same instance
+///
+/// The product with the highest ranked timeliness is preferred, ties are resolved
+/// by choosing the most recently created product. Products without a timeliness
+/// rank lowest.
+pub fn best_of(products: &[Product]) -> Option<&Product> {
+    products.iter().max_by_key(|p| {
+        (
+            p.timeliness.map(|t| t.quality_rank()).unwrap_or(0),
+            p.product_creation_datetime,
+        )
+    })
+}
+
 impl_from_str!(parse_product, Product);
 
 #[cfg(test)]
 mod tests {
-    use crate::identifiers::sentinel3::parse_product;
+    use crate::identifiers::sentinel3::{
+        best_of, parse_instance, parse_platform, parse_product, platform_code, DataSource,
+        DataType, Domain, InstanceId, MissionId, Platform, ResolutionClass, Timeliness,
+    };
     use crate::identifiers::tests::apply_to_samples_from_txt;
+    use crate::{Name, NameLong, ParseError};
+    use std::str::FromStr;
+
+    #[test]
+    fn creation_before_stop_is_rejected() {
+        assert!(parse_product(
+            "S3A_OL_1_EFR____20220801T210143_20220801T210443_20220801T210300_0179_088_157_1800_MAR_O_NT_002"
+        )
+        .is_err());
+
+        // created at the stop time
+        assert!(parse_product(
+            "S3A_OL_1_EFR____20220801T210143_20220801T210443_20220801T210443_0179_088_157_1800_MAR_O_NT_002"
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn data_type_from_str() {
+        assert_eq!(DataType::from_str("EFR___").unwrap(), DataType::EFR);
+        assert_eq!(DataType::from_str("efr").unwrap(), DataType::EFR);
+        assert_eq!(DataType::from_str("WST_BW").unwrap(), DataType::WST_BW);
+        assert_eq!(
+            DataType::from_str("ZZZ___").unwrap(),
+            DataType::Other("ZZZ".to_string())
+        );
+        assert!(matches!(
+            DataType::from_str(""),
+            Err(ParseError::NotEnoughData(0))
+        ));
+        assert_eq!(
+            DataType::from_str("EFR____X").unwrap_err().error_pos(),
+            Some(6)
+        );
+    }
+
+    #[test]
+    fn unknown_data_type_display_roundtrip() {
+        let s = "S3A_OL_2_ZZZ____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002";
+        let (_, product) = parse_product(s).unwrap();
+        assert_eq!(product.data_type, DataType::Other("ZZZ".to_string()));
+        assert_eq!(product.to_string(), s);
+
+        let s = "S3A_SL_2_Z_Z____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002";
+        let (_, product) = parse_product(s).unwrap();
+        assert_eq!(product.data_type, DataType::Other("Z_Z".to_string()));
+        assert_eq!(product.to_string(), s);
+    }
+
+    #[test]
+    fn data_source_name() {
+        assert_eq!(DataSource::OLCI.name(), "OLCI");
+        assert_eq!(
+            DataSource::OLCI.name_long(),
+            "Ocean and Land Colour Instrument"
+        );
+        assert_eq!(DataSource::SLSTR.name(), "SLSTR");
+        assert_eq!(
+            DataSource::SLSTR.name_long(),
+            "Sea and Land Surface Temperature Radiometer"
+        );
+        assert_eq!(DataSource::SRAL.name_long(), "SAR Radar Altimeter");
+    }
+
+    #[test]
+    fn mission_platform_timeliness_name() {
+        assert_eq!(MissionId::S3AB.name(), "S3_");
+        assert_eq!(MissionId::S3B.name_long(), "Sentinel-3B");
+        assert_eq!(Platform::Reference.name(), "F");
+        assert_eq!(Platform::Reference.name_long(), "Reference");
+        assert_eq!(Timeliness::STC.name(), "ST");
+        assert_eq!(Timeliness::STC.name_long(), "Short Time Critical");
+    }
+
+    #[test]
+    fn data_type_name() {
+        assert_eq!(DataType::EFR.name(), "EFR");
+        assert_eq!(DataType::WFR.name(), "WFR");
+        assert_eq!(DataType::AER_AX.name(), "AER_AX");
+        assert_eq!(DataType::MSIR.name(), "MSIR");
+        assert_eq!(DataType::LST.name_long(), "Land Surface Temperature");
+        assert_eq!(DataType::WFR_BW.name_long(), "Water Full Resolution browse");
+        assert_eq!(DataType::WFR.name_long(), "Water Full Resolution");
+        assert_eq!(DataType::Other("ZZZ".to_string()).name(), "ZZZ");
+        assert_eq!(DataType::Other("ZZZ".to_string()).name_long(), "ZZZ");
+
+        for dt in [
+            DataType::EFR,
+            DataType::SYN_BW,
+            DataType::FRP,
+            DataType::Other("ZZZ".to_string()),
+        ] {
+            assert_eq!(DataType::from_str(dt.name()).unwrap(), dt);
+        }
+    }
+
+    #[test]
+    fn platform_code_roundtrip() {
+        for platform in [
+            Some(Platform::Operational),
+            Some(Platform::Reference),
+            Some(Platform::Development),
+            Some(Platform::Reprocessing),
+            None,
+        ] {
+            let code = platform_code(platform).to_string();
+            let (_, parsed) = parse_platform(&code).unwrap();
+            assert_eq!(parsed, platform);
+        }
+        assert_eq!(Platform::Operational.to_string(), "O");
+        assert_eq!(platform_code(None), '_');
+    }
+
+    #[test]
+    fn timeliness_code_roundtrip() {
+        for timeliness in [Timeliness::NRT, Timeliness::STC, Timeliness::NTC] {
+            assert_eq!(
+                Timeliness::from_str(&timeliness.to_string()).unwrap(),
+                timeliness
+            );
+        }
+        assert_eq!(Timeliness::NTC.to_string(), "NT");
+        assert_eq!(Timeliness::from_str("nr").unwrap(), Timeliness::NRT);
+        assert!(Timeliness::from_str("__").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_uses_codes() {
+        assert_eq!(serde_json::to_string(&Timeliness::NTC).unwrap(), r#""NT""#);
+        assert_eq!(
+            serde_json::from_str::<Timeliness>(r#""NT""#).unwrap(),
+            Timeliness::NTC
+        );
+        assert_eq!(
+            serde_json::to_string(&Platform::Operational).unwrap(),
+            r#""O""#
+        );
+        assert_eq!(
+            serde_json::from_str::<Platform>(r#""o""#).unwrap(),
+            Platform::Operational
+        );
+        assert!(serde_json::from_str::<Platform>(r#""_""#).is_err());
+    }
+
+    #[test]
+    fn best_of_prefers_ntc() {
+        let products = [
+            "S3A_OL_1_EFR____20220801T210143_20220801T210443_20220801T224512_0179_088_157_1800_PS1_O_NR_002",
+            "S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002",
+        ]
+        .map(|s| parse_product(s).unwrap().1);
+        let best = best_of(&products).unwrap();
+        assert_eq!(best.timeliness, Some(Timeliness::NTC));
+        assert!(best_of(&[]).is_none());
+    }
+
+    #[test]
+    fn centre_generating_file_preserved() {
+        let (_, product) = parse_product("S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_mar_O_NT_002").unwrap();
+        assert_eq!(product.centre_generating_file.as_str(), "MAR");
+
+        let (_, product) = parse_product("S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_S1__O_NT_002").unwrap();
+        assert_eq!(product.centre_generating_file.as_str(), "S1_");
+    }
+
+    #[test]
+    fn product_resolution_class() {
+        let (_, product) = parse_product("S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002").unwrap();
+        assert_eq!(product.resolution_class(), Some(ResolutionClass::Full));
+
+        let (_, product) = parse_product("S3A_OL_1_ERR____20220801T205215_20220801T213637_20220803T015458_2662_088_157______MAR_O_NT_002").unwrap();
+        assert_eq!(product.resolution_class(), Some(ResolutionClass::Reduced));
+
+        let (_, product) = parse_product("S3B_SL_2_FRP____20221030T181034_20221030T181334_20221030T201327_0179_072_241_0720_PS2_O_NR_004").unwrap();
+        assert_eq!(product.resolution_class(), None);
+    }
+
+    #[test]
+    fn product_nominal_pixel_count_across_track() {
+        let (_, product) = parse_product("S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002").unwrap();
+        assert_eq!(product.nominal_pixel_count_across_track(), Some(4865));
+
+        let (_, product) = parse_product("S3A_OL_1_ERR____20220801T205215_20220801T213637_20220803T015458_2662_088_157______MAR_O_NT_002").unwrap();
+        assert_eq!(product.nominal_pixel_count_across_track(), Some(1217));
+
+        let (_, product) = parse_product("S3B_SL_2_FRP____20221030T181034_20221030T181334_20221030T201327_0179_072_241_0720_PS2_O_NR_004").unwrap();
+        assert_eq!(product.nominal_pixel_count_across_track(), None);
+    }
+
+    #[test]
+    fn product_compositing_period_days() {
+        let (_, product) = parse_product("S3A_SY_2_V10____20221001T000000_20221010T235959_20221013T071219_EUROPE____________LN2_O_NT_002").unwrap();
+        assert_eq!(product.compositing_period_days(), Some(10));
+        assert_eq!(
+            product.instance_id,
+            InstanceId::Tile {
+                tile_identifier: "EUROPE".to_string()
+            }
+        );
+
+        let (_, product) = parse_product("S3B_SY_2_VG1____20221030T000000_20221030T235959_20221101T065403_EUROPE____________LN2_O_NT_002").unwrap();
+        assert_eq!(product.compositing_period_days(), Some(1));
+
+        let (_, product) = parse_product("S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002").unwrap();
+        assert_eq!(product.compositing_period_days(), None);
+    }
+
+    #[test]
+    fn instance_stripe_and_frame_disambiguation() {
+        let (_, product) = parse_product("S3A_OL_1_EFR____20160516T192624_20160516T192924_20180209T163914_0179_004_156_2160_LR2_R_NT_002").unwrap();
+        assert_eq!(
+            product.instance_id,
+            InstanceId::Frame {
+                duration: 179,
+                cycle_number: 4,
+                relative_order_number: 156,
+                frame_along_track_coordinate: 2160,
+            }
+        );
+
+        let (_, product) = parse_product("S3A_OL_1_ERR____20220801T205215_20220801T213637_20220803T015458_2662_088_157______MAR_O_NT_002").unwrap();
+        assert_eq!(
+            product.instance_id,
+            InstanceId::Stripe {
+                duration: 2662,
+                cycle_number: 88,
+                relative_order_number: 157,
+            }
+        );
+
+        // frame coordinate at the start of the orbit
+        let (_, instance) = parse_instance("0179_004_156_0000").unwrap();
+        assert!(matches!(instance, InstanceId::Frame { .. }));
+
+        // neither a frame nor a stripe, must not be mistaken for a tile
+        assert!(parse_instance("0179_004_156_21__").is_err());
+        assert!(parse_instance("0179_004_156_ABCD").is_err());
+    }
+
+    #[test]
+    fn data_source_processing_levels() {
+        let (_, product) = parse_product("S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002").unwrap();
+        assert_eq!(product.data_source, DataSource::OLCI);
+        assert_eq!(product.processing_level, Some(1));
+
+        // DORIS only delivers level 0 data
+        let err = parse_product("S3A_DO_2_NAV____20220801T210143_20220801T210443_20220803T023357_0179_088_157______MAR_O_NT_002").unwrap_err();
+        assert!(matches!(err, nom::Err::Error(e) if e.input.starts_with("2_NAV")));
+        assert!(parse_product("S3A_OL_3_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002").is_err());
+
+        // auxiliary data has no level
+        assert!(DataSource::DORIS.processing_levels().contains(&0));
+        assert!(parse_product("S3A_DO___NAV____20220801T210143_20220801T210443_20220803T023357_0179_088_157______MAR_O_NT_002").is_ok());
+    }
+
+    #[test]
+    fn parse_s3_product_container_extension() {
+        let name = "S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002";
+        let (_, product) = parse_product(name).unwrap();
+        assert_eq!(product.container_extension, None);
+
+        let s = format!("{}.sen3", name);
+        let (rest, product) = parse_product(&s).unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(product.container_extension.as_deref(), Some("SEN3"));
+        assert_eq!(product.collection_or_usage.as_deref(), Some("002"));
+        assert_eq!(product.to_string(), format!("{}.SEN3", name));
+    }
+
+    #[test]
+    fn instance_display_padding() {
+        for (instance, expected) in [
+            (
+                InstanceId::Frame {
+                    duration: 179,
+                    cycle_number: 4,
+                    relative_order_number: 6,
+                    frame_along_track_coordinate: 60,
+                },
+                "0179_004_006_0060",
+            ),
+            (
+                InstanceId::Frame {
+                    duration: 1,
+                    cycle_number: 0,
+                    relative_order_number: 0,
+                    frame_along_track_coordinate: 0,
+                },
+                "0001_000_000_0000",
+            ),
+            (
+                InstanceId::Stripe {
+                    duration: 62,
+                    cycle_number: 88,
+                    relative_order_number: 7,
+                },
+                "0062_088_007_____",
+            ),
+        ] {
+            let formatted = instance.to_string();
+            assert_eq!(formatted.as_str(), expected);
+            assert_eq!(formatted.len(), 17);
+            let (rest, parsed) = parse_instance(&formatted).unwrap();
+            assert!(rest.is_empty());
+            assert_eq!(parsed, instance);
+        }
+    }
+
+    #[test]
+    fn frp_products_are_fire_domain() {
+        apply_to_samples_from_txt("sentinel3_products.txt", |s| {
+            if s.contains("_SL_2_FRP") {
+                let (_, product) = parse_product(s).unwrap();
+                assert_eq!(product.data_type, DataType::FRP);
+                assert_eq!(product.data_type.domain(), Some(Domain::Fire));
+            }
+        });
+        assert_eq!(DataType::LST.domain(), Some(Domain::Land));
+        assert_eq!(DataType::WST.domain(), Some(Domain::Water));
+        assert_eq!(DataType::EFR.domain(), None);
+    }
+
+    #[test]
+    fn product_display_roundtrip() {
+        apply_to_samples_from_txt("sentinel3_products.txt", |s| {
+            let (_, product) = parse_product(s).unwrap();
+            assert_eq!(product.to_string(), s);
+        });
+
+        for s in [
+            "S3A_SY_2_V10____20221001T000000_20221010T235959_20221013T071219_EUROPE____________LN2_O_NT_002",
+            "S3A_OL_1_ERR____20220801T205215_20220801T213637_20220803T015458_2662_088_157______MAR_O_NT_002",
+        ] {
+            let (_, product) = parse_product(s).unwrap();
+            assert_eq!(product.to_string(), s);
+        }
+    }
 
     #[test]
     fn apply_to_product_testdata() {
@@ -1 +1,397 @@
-//! TODO
+//! Sentinel 5P
+//!
+//! [naming convention](https://sentinels.copernicus.eu/web/sentinel/technical-guides/sentinel-5p/products-algorithms)
+//!
+//! # Example
+//!
+//! ```rust
+//! use eo_identifiers::identifiers::sentinel5p::Product;
+//! use std::str::FromStr;
+//!
+//! assert!(
+//!     Product::from_str("S5P_OFFL_L2__NO2____20230101T005953_20230101T024123_21890_03_020400_20230102T170000")
+//!     .is_ok()
+//! );
+//! ```
+use crate::common_parsers::{
+    format_esa_timestamp, is_char_alphanumeric, parse_esa_timestamp, take_n_digits,
+};
+use crate::{impl_from_str, Mission, Name, NameLong, ParseError, Timeliness};
+use alloc::string::String;
+use chrono::NaiveDateTime;
+use core::fmt;
+use core::str::FromStr;
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case, take_while_m_n};
+use nom::character::complete::char;
+use nom::combinator::{map, map_res};
+use nom::IResult;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MissionId {
+    S5P,
+}
+
+impl From<MissionId> for Mission {
+    fn from(_: MissionId) -> Self {
+        Mission::Sentinel5p
+    }
+}
+
+/// file class, the processing stream of the product
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FileClass {
+    /// Near-Real-Time
+    NRTI,
+
+    /// Offline
+    OFFL,
+
+    /// Reprocessing
+    RPRO,
+
+    /// test data
+    TEST,
+}
+
+impl FileClass {
+    /// code as used in identifiers
+    pub fn code(&self) -> &'static str {
+        match self {
+            FileClass::NRTI => "NRTI",
+            FileClass::OFFL => "OFFL",
+            FileClass::RPRO => "RPRO",
+            FileClass::TEST => "TEST",
+        }
+    }
+
+    /// the unified timeliness, test data has none
+    pub fn timeliness(&self) -> Option<Timeliness> {
+        match self {
+            FileClass::NRTI => Some(Timeliness::NRT),
+            FileClass::OFFL => Some(Timeliness::OFFL),
+            FileClass::RPRO => Some(Timeliness::RPRO),
+            FileClass::TEST => None,
+        }
+    }
+}
+
+/// data product of the TROPOMI instrument
+///
+/// The level 2 products have their own variants, level 1B products like the radiances
+/// (`RA_BD1`, ...) and irradiances (`IR_UVN`, ...) are kept as [`Product5pType::Other`].
+#[allow(non_camel_case_types)]
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Product5pType {
+    NO2,
+    CH4,
+    CO,
+    O3,
+    SO2,
+    HCHO,
+    CLOUD,
+    AER_AI,
+    Other(String),
+}
+
+impl Name for Product5pType {
+    /// the code as used in the product name, without the `_` padding
+    fn name(&self) -> &str {
+        match self {
+            Product5pType::NO2 => "NO2",
+            Product5pType::CH4 => "CH4",
+            Product5pType::CO => "CO",
+            Product5pType::O3 => "O3",
+            Product5pType::SO2 => "SO2",
+            Product5pType::HCHO => "HCHO",
+            Product5pType::CLOUD => "CLOUD",
+            Product5pType::AER_AI => "AER_AI",
+            Product5pType::Other(s) => s.as_str(),
+        }
+    }
+}
+
+impl NameLong for Product5pType {
+    fn name_long(&self) -> &str {
+        match self {
+            Product5pType::NO2 => "Nitrogen Dioxide",
+            Product5pType::CH4 => "Methane",
+            Product5pType::CO => "Carbon Monoxide",
+            Product5pType::O3 => "Ozone",
+            Product5pType::SO2 => "Sulphur Dioxide",
+            Product5pType::HCHO => "Formaldehyde",
+            Product5pType::CLOUD => "Cloud",
+            Product5pType::AER_AI => "UV Aerosol Index",
+            Product5pType::Other(s) => s.as_str(),
+        }
+    }
+}
+
+impl FromStr for Product5pType {
+    type Err = ParseError;
+
+    /// parse the product type code, the code may be given with or without the `_` padding
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code = s.trim_end_matches('_').to_uppercase();
+        Ok(match code.as_str() {
+            "" => return Err(ParseError::NotEnoughData(0)),
+            "NO2" => Product5pType::NO2,
+            "CH4" => Product5pType::CH4,
+            "CO" => Product5pType::CO,
+            "O3" => Product5pType::O3,
+            "SO2" => Product5pType::SO2,
+            "HCHO" => Product5pType::HCHO,
+            "CLOUD" => Product5pType::CLOUD,
+            "AER_AI" => Product5pType::AER_AI,
+            _ => Product5pType::Other(code),
+        })
+    }
+}
+
+/// Sentinel 5P product
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Product {
+    /// mission id
+    pub mission_id: MissionId,
+
+    /// file class
+    pub file_class: FileClass,
+
+    /// processing level, level 1 products are always `L1B`
+    pub product_level: u8,
+
+    /// data product
+    pub product_identifier: Product5pType,
+
+    /// sensing start datetime
+    pub start_datetime: NaiveDateTime,
+
+    /// sensing stop datetime
+    pub stop_datetime: NaiveDateTime,
+
+    /// absolute orbit number
+    pub orbit_number: u32,
+
+    /// collection number
+    pub collection_number: u8,
+
+    /// processor version as major, minor and patch version
+    pub processor_version: (u8, u8, u8),
+
+    /// processing datetime
+    pub processing_datetime: NaiveDateTime,
+}
+
+impl fmt::Display for Product {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level_padding = if self.product_level == 1 { "B_" } else { "__" };
+        write!(
+            f,
+            "S5P_{}_L{}{}{:_<6}_{}_{}_{:05}_{:02}_{:02}{:02}{:02}_{}",
+            self.file_class.code(),
+            self.product_level,
+            level_padding,
+            self.product_identifier.name(),
+            format_esa_timestamp(&self.start_datetime),
+            format_esa_timestamp(&self.stop_datetime),
+            self.orbit_number,
+            self.collection_number,
+            self.processor_version.0,
+            self.processor_version.1,
+            self.processor_version.2,
+            format_esa_timestamp(&self.processing_datetime),
+        )
+    }
+}
+
+fn consume_product_sep(s: &str) -> IResult<&str, core::primitive::char> {
+    char('_')(s)
+}
+
+fn parse_file_class(s: &str) -> IResult<&str, FileClass> {
+    alt((
+        map(tag_no_case("nrti"), |_| FileClass::NRTI),
+        map(tag_no_case("offl"), |_| FileClass::OFFL),
+        map(tag_no_case("rpro"), |_| FileClass::RPRO),
+        map(tag_no_case("test"), |_| FileClass::TEST),
+    ))(s)
+}
+
+fn parse_product_level(s: &str) -> IResult<&str, u8> {
+    let (s, _) = tag_no_case("l")(s)?;
+    let (s, level) = take_n_digits(1)(s)?;
+    // only level 1 products carry the `B` of `L1B`
+    let (s, _) = if level == 1 {
+        tag_no_case("b_")(s)?
+    } else {
+        tag("__")(s)?
+    };
+    Ok((s, level))
+}
+
+fn parse_product_identifier(s: &str) -> IResult<&str, Product5pType> {
+    map_res(
+        take_while_m_n(6, 6, |c| is_char_alphanumeric(c) || c == '_'),
+        Product5pType::from_str,
+    )(s)
+}
+
+/// nom parser function
+pub fn parse_product(s: &str) -> IResult<&str, Product> {
+    let (s, _) = tag_no_case("s5p")(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, file_class) = parse_file_class(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, product_level) = parse_product_level(s)?;
+    let (s, product_identifier) = parse_product_identifier(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, start_datetime) = parse_esa_timestamp(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, stop_datetime) = parse_esa_timestamp(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, orbit_number) = take_n_digits(5)(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, collection_number) = take_n_digits(2)(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, major) = take_n_digits(2)(s)?;
+    let (s, minor) = take_n_digits(2)(s)?;
+    let (s, patch) = take_n_digits(2)(s)?;
+    let (s, _) = consume_product_sep(s)?;
+    let (s, processing_datetime) = parse_esa_timestamp(s)?;
+
+    Ok((
+        s,
+        Product {
+            mission_id: MissionId::S5P,
+            file_class,
+            product_level,
+            product_identifier,
+            start_datetime,
+            stop_datetime,
+            orbit_number,
+            collection_number,
+            processor_version: (major, minor, patch),
+            processing_datetime,
+        },
+    ))
+}
+
+impl_from_str!(parse_product, Product);
+
+#[cfg(test)]
+mod tests {
+    use crate::identifiers::sentinel5p::{parse_product, FileClass, Product5pType};
+    use crate::identifiers::tests::apply_to_samples_from_txt;
+    use crate::{Name, NameLong};
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+    use std::str::FromStr;
+
+    #[test]
+    fn parse_s5p_product() {
+        let (_, product) = parse_product(
+            "S5P_OFFL_L2__NO2____20230101T005953_20230101T024123_21890_03_020400_20230102T170000",
+        )
+        .unwrap();
+        assert_eq!(product.file_class, FileClass::OFFL);
+        assert_eq!(product.product_level, 2);
+        assert_eq!(product.product_identifier, Product5pType::NO2);
+        assert_eq!(
+            product.start_datetime,
+            NaiveDateTime::new(
+                NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                NaiveTime::from_hms_opt(0, 59, 53).unwrap()
+            )
+        );
+        assert_eq!(product.orbit_number, 21890);
+        assert_eq!(product.collection_number, 3);
+        assert_eq!(product.processor_version, (2, 4, 0));
+        assert_eq!(
+            product.processing_datetime,
+            NaiveDateTime::new(
+                NaiveDate::from_ymd_opt(2023, 1, 2).unwrap(),
+                NaiveTime::from_hms_opt(17, 0, 0).unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn parse_s5p_product_with_underscore_in_type() {
+        let (_, product) = parse_product(
+            "S5P_OFFL_L2__AER_AI_20220104T081710_20220104T095840_21905_02_020301_20220105T220852",
+        )
+        .unwrap();
+        assert_eq!(product.product_identifier, Product5pType::AER_AI);
+    }
+
+    #[test]
+    fn parse_s5p_l1b_product() {
+        let (_, product) = parse_product(
+            "S5P_OFFL_L1B_RA_BD1_20180816T104424_20180816T122552_04349_01_010000_20180816T141434",
+        )
+        .unwrap();
+        assert_eq!(product.product_level, 1);
+        assert_eq!(
+            product.product_identifier,
+            Product5pType::Other("RA_BD1".to_string())
+        );
+    }
+
+    #[test]
+    fn b_only_follows_level_1() {
+        assert!(parse_product(
+            "S5P_OFFL_L2B_NO2____20230101T005953_20230101T024123_21890_03_020400_20230102T170000"
+        )
+        .is_err());
+        assert!(parse_product(
+            "S5P_OFFL_L1__RA_BD1_20180816T104424_20180816T122552_04349_01_010000_20180816T141434"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn apply_to_product_testdata() {
+        apply_to_samples_from_txt("sentinel5p_products.txt", |s| {
+            parse_product(s).unwrap();
+        })
+    }
+
+    #[test]
+    fn product_display_roundtrip() {
+        apply_to_samples_from_txt("sentinel5p_products.txt", |s| {
+            let (_, product) = parse_product(s).unwrap();
+            assert_eq!(product.to_string(), s);
+        })
+    }
+
+    #[test]
+    fn product_type_from_str() {
+        assert_eq!(
+            Product5pType::from_str("NO2___").unwrap(),
+            Product5pType::NO2
+        );
+        assert_eq!(Product5pType::from_str("ch4").unwrap(), Product5pType::CH4);
+        assert_eq!(
+            Product5pType::from_str("AER_AI").unwrap(),
+            Product5pType::AER_AI
+        );
+        assert_eq!(
+            Product5pType::from_str("O3_TCL").unwrap(),
+            Product5pType::Other("O3_TCL".to_string())
+        );
+        assert!(Product5pType::from_str("______").is_err());
+    }
+
+    #[test]
+    fn product_type_name() {
+        assert_eq!(Product5pType::NO2.name(), "NO2");
+        assert_eq!(Product5pType::NO2.name_long(), "Nitrogen Dioxide");
+        assert_eq!(Product5pType::CH4.name_long(), "Methane");
+        assert_eq!(Product5pType::Other("O3_TCL".to_string()).name(), "O3_TCL");
+    }
+}
@@ -26,14 +26,45 @@
 //!     unreachable!();
 //! }
 //! ```
+//!
+//! # Features
+//!
+//! The `std` feature is enabled by default. Without it the crate is `no_std`, but still
+//! requires `alloc`. Functionality depending on the standard library - for example
+//! `Identifier::from_path` - is only available with `std`.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "cache")]
+mod cache;
 mod common_parsers;
 mod from_str;
 pub mod identifiers;
+pub mod prelude;
+#[cfg(feature = "serde")]
+pub mod serde_str;
+#[cfg(feature = "serde")]
+pub mod serde_tagged;
+#[cfg(feature = "stac")]
+mod stac;
 
-use chrono::NaiveDateTime;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+use core::cmp::Ordering;
+use core::fmt;
 pub use nom;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::path::PathBuf;
 
-pub use from_str::ParseError;
+#[cfg(feature = "cache")]
+pub use cache::{parse_cached, CACHE_CAPACITY};
+pub use from_str::{ParseError, PartialIdentifier};
 
 // Writing Parsers With nom Parser Combinator Framework: https://iximiuz.com/en/posts/rust-writing-parsers-with-nom/
 
@@ -48,12 +79,44 @@ pub trait NameLong {
     fn name_long(&self) -> &str;
 }
 
+/// date as the day of the year, as used by the Julian dates (`YYYYDDD`) of the naming
+/// conventions of Landsat, MODIS and HLS
+pub trait JulianDate {
+    /// day of the year, starting with 1 on January 1st
+    fn day_of_year(&self) -> u32;
+
+    /// the year followed by the three digit day of the year (`YYYYDDD`)
+    fn julian_string(&self) -> String;
+}
+
+impl JulianDate for NaiveDate {
+    fn day_of_year(&self) -> u32 {
+        self.ordinal()
+    }
+
+    fn julian_string(&self) -> String {
+        format!("{:04}{:03}", self.year(), self.ordinal())
+    }
+}
+
+/// the date of the sensing start
+impl JulianDate for Identifier {
+    fn day_of_year(&self) -> u32 {
+        self.start_datetime().date().day_of_year()
+    }
+
+    fn julian_string(&self) -> String {
+        self.start_datetime().date().julian_string()
+    }
+}
+
 #[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Copy, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Mission {
     Sentinel1,
     Sentinel2,
     Sentinel3,
+    Sentinel5p,
     Landsat1,
     Landsat2,
     Landsat3,
@@ -63,6 +126,9 @@ pub enum Mission {
     Landsat7,
     Landsat8,
     Landsat9,
+    Hls,
+    Modis,
+    Planet,
 }
 
 impl Name for Mission {
@@ -71,6 +137,7 @@ impl Name for Mission {
             Mission::Sentinel1 => "Sentinel 1",
             Mission::Sentinel2 => "Sentinel 2",
             Mission::Sentinel3 => "Sentinel 3",
+            Mission::Sentinel5p => "Sentinel 5P",
             Mission::Landsat1 => "Landsat 1",
             Mission::Landsat2 => "Landsat 2",
             Mission::Landsat3 => "Landsat 3",
@@ -80,10 +147,81 @@ impl Name for Mission {
             Mission::Landsat7 => "Landsat 7",
             Mission::Landsat8 => "Landsat 8",
             Mission::Landsat9 => "Landsat 9",
+            Mission::Hls => "Harmonized Landsat Sentinel-2",
+            Mission::Modis => "MODIS",
+            Mission::Planet => "PlanetScope",
+        }
+    }
+}
+
+impl Mission {
+    /// lowercase, hyphenated slug for use in URLs and paths, for example `sentinel-2`
+    pub fn slug(&self) -> &'static str {
+        match self {
+            Mission::Sentinel1 => "sentinel-1",
+            Mission::Sentinel2 => "sentinel-2",
+            Mission::Sentinel3 => "sentinel-3",
+            Mission::Sentinel5p => "sentinel-5p",
+            Mission::Landsat1 => "landsat-1",
+            Mission::Landsat2 => "landsat-2",
+            Mission::Landsat3 => "landsat-3",
+            Mission::Landsat4 => "landsat-4",
+            Mission::Landsat5 => "landsat-5",
+            Mission::Landsat6 => "landsat-6",
+            Mission::Landsat7 => "landsat-7",
+            Mission::Landsat8 => "landsat-8",
+            Mission::Landsat9 => "landsat-9",
+            Mission::Hls => "hls",
+            Mission::Modis => "modis",
+            Mission::Planet => "planet",
+        }
+    }
+}
+
+/// Timeliness of a product, unified across missions
+#[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Timeliness {
+    /// Near-Real-Time
+    NRT,
+
+    /// Short-Time-Critical
+    STC,
+
+    /// Non-Time-Critical
+    NTC,
+
+    /// Offline
+    OFFL,
+
+    /// Reprocessing
+    RPRO,
+}
+
+impl From<identifiers::sentinel3::Timeliness> for Timeliness {
+    fn from(t: identifiers::sentinel3::Timeliness) -> Self {
+        match t {
+            identifiers::sentinel3::Timeliness::NRT => Self::NRT,
+            identifiers::sentinel3::Timeliness::STC => Self::STC,
+            identifiers::sentinel3::Timeliness::NTC => Self::NTC,
         }
     }
 }
 
+/// Static facts about an instrument
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Capabilities {
+    /// number of spectral bands, including thermal bands
+    pub spectral_bands: u8,
+
+    /// the instrument has thermal infrared bands
+    pub has_thermal_bands: bool,
+
+    /// approximate swath width in km
+    pub swath_width_km: u32,
+}
+
 /// Identifier of a earth observation product or dataset
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialOrd, PartialEq, Eq, Debug, Clone, Hash)]
@@ -91,9 +229,14 @@ pub enum Identifier {
     Sentinel1Product(identifiers::sentinel1::Product),
     Sentinel1Dataset(identifiers::sentinel1::Dataset),
     Sentinel2Product(identifiers::sentinel2::Product),
+    Sentinel2LongProduct(identifiers::sentinel2::LongProduct),
     Sentinel3Product(identifiers::sentinel3::Product),
+    Sentinel5pProduct(identifiers::sentinel5p::Product),
     LandsatSceneId(identifiers::landsat::SceneId),
     LandsatProduct(identifiers::landsat::Product),
+    HlsProduct(identifiers::hls::Product),
+    ModisProduct(identifiers::modis::Product),
+    PlanetScene(identifiers::planet::Scene),
 }
 
 impl From<identifiers::sentinel1::Product> for Identifier {
@@ -114,12 +257,24 @@ impl From<identifiers::sentinel2::Product> for Identifier {
     }
 }
 
+impl From<identifiers::sentinel2::LongProduct> for Identifier {
+    fn from(p: identifiers::sentinel2::LongProduct) -> Self {
+        Self::Sentinel2LongProduct(p)
+    }
+}
+
 impl From<identifiers::sentinel3::Product> for Identifier {
     fn from(p: identifiers::sentinel3::Product) -> Self {
         Self::Sentinel3Product(p)
     }
 }
 
+impl From<identifiers::sentinel5p::Product> for Identifier {
+    fn from(p: identifiers::sentinel5p::Product) -> Self {
+        Self::Sentinel5pProduct(p)
+    }
+}
+
 impl From<identifiers::landsat::SceneId> for Identifier {
     fn from(p: identifiers::landsat::SceneId) -> Self {
         Self::LandsatSceneId(p)
@@ -132,6 +287,48 @@ impl From<identifiers::landsat::Product> for Identifier {
     }
 }
 
+impl From<identifiers::hls::Product> for Identifier {
+    fn from(p: identifiers::hls::Product) -> Self {
+        Self::HlsProduct(p)
+    }
+}
+
+impl From<identifiers::modis::Product> for Identifier {
+    fn from(p: identifiers::modis::Product) -> Self {
+        Self::ModisProduct(p)
+    }
+}
+
+impl From<identifiers::planet::Scene> for Identifier {
+    fn from(s: identifiers::planet::Scene) -> Self {
+        Self::PlanetScene(s)
+    }
+}
+
+/// Formats the identifier in its canonical form
+///
+/// The canonical form may differ from the parsed string: all parts are uppercased - for
+/// example the ground station identifier of Landsat scene ids - and file extensions like
+/// `.SAFE` are dropped when parsing from a string. PlanetScope scenes are the exception, they
+/// keep the lowercase satellite ids and the asset types used by Planet.
+impl fmt::Display for Identifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Identifier::Sentinel1Product(p) => p.fmt(f),
+            Identifier::Sentinel1Dataset(ds) => ds.fmt(f),
+            Identifier::Sentinel2Product(p) => p.fmt(f),
+            Identifier::Sentinel2LongProduct(p) => p.fmt(f),
+            Identifier::Sentinel3Product(p) => p.fmt(f),
+            Identifier::Sentinel5pProduct(p) => p.fmt(f),
+            Identifier::LandsatSceneId(s) => s.fmt(f),
+            Identifier::LandsatProduct(p) => p.fmt(f),
+            Identifier::HlsProduct(p) => p.fmt(f),
+            Identifier::ModisProduct(p) => p.fmt(f),
+            Identifier::PlanetScene(s) => s.fmt(f),
+        }
+    }
+}
+
 impl Identifier {
     /// mission
     pub fn mission(&self) -> Mission {
@@ -139,9 +336,14 @@ impl Identifier {
             Identifier::Sentinel1Product(p) => p.mission_id.into(),
             Identifier::Sentinel1Dataset(ds) => ds.mission_id.into(),
             Identifier::Sentinel2Product(p) => p.mission_id.into(),
+            Identifier::Sentinel2LongProduct(p) => p.mission_id.into(),
             Identifier::Sentinel3Product(p) => p.mission_id.into(),
+            Identifier::Sentinel5pProduct(p) => p.mission_id.into(),
             Identifier::LandsatSceneId(s) => s.mission.into(),
             Identifier::LandsatProduct(p) => p.mission.into(),
+            Identifier::HlsProduct(p) => p.sub_product.into(),
+            Identifier::ModisProduct(p) => p.platform.into(),
+            Identifier::PlanetScene(_) => Mission::Planet,
         }
     }
 
@@ -151,13 +353,18 @@ impl Identifier {
             Identifier::Sentinel1Product(p) => p.start_datetime,
             Identifier::Sentinel1Dataset(ds) => ds.start_datetime,
             Identifier::Sentinel2Product(p) => p.start_datetime,
+            Identifier::Sentinel2LongProduct(p) => p.validity_start_datetime,
             Identifier::Sentinel3Product(p) => p.start_datetime,
+            Identifier::Sentinel5pProduct(p) => p.start_datetime,
             Identifier::LandsatSceneId(s) => {
                 s.acquire_date.and_hms_opt(0, 0, 0).expect("valid time")
             }
             Identifier::LandsatProduct(p) => {
                 p.acquire_date.and_hms_opt(0, 0, 0).expect("valid time")
             }
+            Identifier::HlsProduct(p) => p.start_datetime,
+            Identifier::ModisProduct(p) => p.acquire_date.and_hms_opt(0, 0, 0).expect("valid time"),
+            Identifier::PlanetScene(s) => s.acquire_datetime,
         }
     }
 
@@ -167,9 +374,1023 @@ impl Identifier {
             Identifier::Sentinel1Product(p) => Some(p.stop_datetime),
             Identifier::Sentinel1Dataset(ds) => Some(ds.stop_datetime),
             Identifier::Sentinel2Product(_) => None,
+            Identifier::Sentinel2LongProduct(p) => Some(p.validity_stop_datetime),
             Identifier::Sentinel3Product(p) => Some(p.stop_datetime),
+            Identifier::Sentinel5pProduct(p) => Some(p.stop_datetime),
             Identifier::LandsatSceneId(_) => None,
             Identifier::LandsatProduct(_) => None,
+            Identifier::HlsProduct(_) => None,
+            Identifier::ModisProduct(_) => None,
+            Identifier::PlanetScene(_) => None,
+        }
+    }
+
+    /// sensing start and stop datetime as RFC 3339 UTC strings, for example `2017-01-05T01:34:42Z`
+    ///
+    /// Suitable for the `datetime`, `start_datetime` and `end_datetime` fields of STAC items.
+    pub fn stac_datetime_strings(&self) -> (String, Option<String>) {
+        let format = |dt: NaiveDateTime| dt.format("%Y-%m-%dT%H:%M:%SZ").to_string();
+        (
+            format(self.start_datetime()),
+            self.stop_datetime().map(format),
+        )
+    }
+
+    /// relative orbit number, as far as it is encoded in the identifier
+    pub fn relative_orbit(&self) -> Option<u32> {
+        use identifiers::sentinel3::InstanceId;
+
+        match self {
+            Identifier::Sentinel1Product(p) => Some(p.relative_orbit() as u32),
+            Identifier::Sentinel1Dataset(ds) => Some(ds.relative_orbit() as u32),
+            Identifier::Sentinel2Product(p) => Some(p.relative_orbit_number as u32),
+            Identifier::Sentinel2LongProduct(p) => Some(p.relative_orbit_number as u32),
+            Identifier::Sentinel3Product(p) => match p.instance_id {
+                InstanceId::Stripe {
+                    relative_order_number,
+                    ..
+                }
+                | InstanceId::Frame {
+                    relative_order_number,
+                    ..
+                } => Some(relative_order_number),
+                _ => None,
+            },
+            Identifier::Sentinel5pProduct(_)
+            | Identifier::LandsatSceneId(_)
+            | Identifier::LandsatProduct(_)
+            | Identifier::HlsProduct(_)
+            | Identifier::ModisProduct(_)
+            | Identifier::PlanetScene(_) => None,
+        }
+    }
+
+    /// timeliness of the product, as far as it is encoded in the identifier
+    pub fn timeliness(&self) -> Option<Timeliness> {
+        match self {
+            Identifier::Sentinel3Product(p) => p.timeliness.map(Timeliness::from),
+            Identifier::Sentinel5pProduct(p) => p.file_class.timeliness(),
+            Identifier::Sentinel1Product(_)
+            | Identifier::Sentinel1Dataset(_)
+            | Identifier::Sentinel2Product(_)
+            | Identifier::Sentinel2LongProduct(_)
+            | Identifier::LandsatSceneId(_)
+            | Identifier::LandsatProduct(_)
+            | Identifier::HlsProduct(_)
+            | Identifier::ModisProduct(_)
+            | Identifier::PlanetScene(_) => None,
+        }
+    }
+
+    /// check if the product has been reprocessed, as far as this can be derived from the
+    /// identifier
+    ///
+    /// Sentinel-3 products encode this in the platform, Sentinel-5P products in the file
+    /// class. Landsat products are considered reprocessed when they were processed more than
+    /// 90 days after the acquisition, as the regular processing - including the tier
+    /// assignment - completes within a few weeks.
+    pub fn is_reprocessed(&self) -> Option<bool> {
+        use identifiers::sentinel3::Platform;
+        use identifiers::sentinel5p::FileClass;
+
+        match self {
+            Identifier::Sentinel3Product(p) => p.platform.map(|pf| pf == Platform::Reprocessing),
+            Identifier::Sentinel5pProduct(p) => Some(p.file_class == FileClass::RPRO),
+            Identifier::LandsatProduct(p) => {
+                Some((p.processing_date - p.acquire_date).num_days() > 90)
+            }
+            Identifier::Sentinel1Product(_)
+            | Identifier::Sentinel1Dataset(_)
+            | Identifier::Sentinel2Product(_)
+            | Identifier::Sentinel2LongProduct(_)
+            | Identifier::LandsatSceneId(_)
+            | Identifier::HlsProduct(_)
+            | Identifier::ModisProduct(_)
+            | Identifier::PlanetScene(_) => None,
+        }
+    }
+
+    /// datetime the product was processed, as far as it is encoded in the identifier
+    ///
+    /// Useful to keep only the most recently processed product of several reprocessings.
+    /// Landsat only encodes the processing date, so the time is always midnight.
+    pub fn processing_recency(&self) -> Option<NaiveDateTime> {
+        match self {
+            Identifier::Sentinel2Product(p) => p.discriminator_datetime(),
+            Identifier::Sentinel2LongProduct(p) => Some(p.processing_datetime),
+            Identifier::Sentinel3Product(p) => Some(p.product_creation_datetime),
+            Identifier::Sentinel5pProduct(p) => Some(p.processing_datetime),
+            Identifier::LandsatProduct(p) => p.processing_date.and_hms_opt(0, 0, 0),
+            Identifier::ModisProduct(p) => Some(p.production_datetime),
+            Identifier::Sentinel1Product(_)
+            | Identifier::Sentinel1Dataset(_)
+            | Identifier::LandsatSceneId(_)
+            | Identifier::HlsProduct(_)
+            | Identifier::PlanetScene(_) => None,
+        }
+    }
+
+    /// instrument which acquired the data, as lowercase slug
+    pub fn instrument(&self) -> &'static str {
+        use identifiers::hls::SubProduct;
+        use identifiers::landsat::Sensor;
+        use identifiers::sentinel3::DataSource;
+
+        match self {
+            Identifier::Sentinel1Product(_) | Identifier::Sentinel1Dataset(_) => "c-sar",
+            Identifier::Sentinel2Product(_) | Identifier::Sentinel2LongProduct(_) => "msi",
+            Identifier::Sentinel3Product(p) => match p.data_source {
+                DataSource::OLCI => "olci",
+                DataSource::SLSTR => "slstr",
+                DataSource::Synergy => "synergy",
+                DataSource::SRAL => "sral",
+                DataSource::DORIS => "doris",
+                DataSource::MWR => "mwr",
+                DataSource::GNSS => "gnss",
+            },
+            Identifier::Sentinel5pProduct(_) => "tropomi",
+            Identifier::LandsatSceneId(identifiers::landsat::SceneId { sensor, .. })
+            | Identifier::LandsatProduct(identifiers::landsat::Product { sensor, .. }) => {
+                match sensor {
+                    Sensor::OLI_TRIS => "oli-tirs",
+                    Sensor::OLI => "oli",
+                    Sensor::TIRS => "tirs",
+                    Sensor::ETM_PLUS => "etm-plus",
+                    Sensor::TM => "tm",
+                    Sensor::MSS => "mss",
+                }
+            }
+            Identifier::HlsProduct(p) => match p.sub_product {
+                SubProduct::S30 => "msi",
+                SubProduct::L30 => "oli",
+            },
+            Identifier::ModisProduct(_) => "modis",
+            Identifier::PlanetScene(_) => "planetscope",
+        }
+    }
+
+    /// static facts about the instrument, for optical and thermal instruments
+    pub fn capabilities(&self) -> Option<Capabilities> {
+        match self {
+            Identifier::Sentinel2Product(_) | Identifier::Sentinel2LongProduct(_) => {
+                Some(identifiers::sentinel2::MSI_CAPABILITIES)
+            }
+            Identifier::Sentinel3Product(p) => p.data_source.capabilities(),
+            Identifier::LandsatSceneId(identifiers::landsat::SceneId { sensor, .. })
+            | Identifier::LandsatProduct(identifiers::landsat::Product { sensor, .. }) => {
+                Some(sensor.capabilities())
+            }
+            Identifier::ModisProduct(_) => Some(identifiers::modis::MODIS_CAPABILITIES),
+            Identifier::Sentinel1Product(_)
+            | Identifier::Sentinel1Dataset(_)
+            | Identifier::Sentinel5pProduct(_)
+            | Identifier::HlsProduct(_)
+            | Identifier::PlanetScene(_) => None,
+        }
+    }
+
+    /// header of the columns returned by [`Identifier::to_csv_row`]
+    pub fn csv_header() -> Vec<&'static str> {
+        vec![
+            "mission",
+            "datetime",
+            "level",
+            "spatial_key",
+            "relative_orbit",
+        ]
+    }
+
+    /// flattened, mission-agnostic row for CSV exports
+    ///
+    /// Columns which are not applicable to the identifier are empty. The spatial key is the
+    /// tile for tiled products - `hHHvVV` for MODIS - and the WRS path and row (`PPPRRR`)
+    /// for Landsat.
+    pub fn to_csv_row(&self) -> Vec<String> {
+        use identifiers::sentinel3::InstanceId;
+
+        let spatial_key = match self {
+            Identifier::Sentinel2Product(p) => p.tile_number.clone(),
+            Identifier::Sentinel3Product(p) => match &p.instance_id {
+                InstanceId::Tile { tile_identifier } => tile_identifier.clone(),
+                _ => String::new(),
+            },
+            Identifier::LandsatSceneId(identifiers::landsat::SceneId {
+                wrs_path, wrs_row, ..
+            })
+            | Identifier::LandsatProduct(identifiers::landsat::Product {
+                wrs_path, wrs_row, ..
+            }) => format!("{:03}{:03}", wrs_path, wrs_row),
+            Identifier::HlsProduct(p) => p.tile_number.clone(),
+            Identifier::ModisProduct(p) => {
+                format!("h{:02}v{:02}", p.horizontal_tile, p.vertical_tile)
+            }
+            Identifier::Sentinel1Product(_)
+            | Identifier::Sentinel1Dataset(_)
+            | Identifier::Sentinel2LongProduct(_)
+            | Identifier::Sentinel5pProduct(_)
+            | Identifier::PlanetScene(_) => String::new(),
+        };
+        vec![
+            self.mission().slug().to_string(),
+            self.stac_datetime_strings().0,
+            self.level().unwrap_or_default(),
+            spatial_key,
+            self.relative_orbit()
+                .map(|o| o.to_string())
+                .unwrap_or_default(),
+        ]
+    }
+
+    /// single line of `key=value` pairs, for example for logging
+    ///
+    /// Contains the `mission` slug, the processing `level`, the location, the relative `orbit`
+    /// and the `date` of the start of the acquisition, separated by spaces. The location is
+    /// the `tile` for tiled products - prefixed with `T` for the MGRS tiles of Sentinel-2 and
+    /// HLS -, the WRS `path` and `row` for Landsat and the sinusoidal grid tile as `h` and `v`
+    /// for MODIS. Keys not applicable to the identifier are omitted.
+    ///
+    /// ```rust
+    /// use eo_identifiers::Identifier;
+    /// use std::str::FromStr;
+    ///
+    /// let ident = Identifier::from_str(
+    ///     "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443",
+    /// ).unwrap();
+    /// assert_eq!(
+    ///     ident.to_kv_line(),
+    ///     "mission=sentinel-2 level=L1C tile=T53NMJ orbit=31 date=2017-01-05"
+    /// );
+    /// ```
+    pub fn to_kv_line(&self) -> String {
+        let mut pairs = vec![format!("mission={}", self.mission().slug())];
+        if let Some(level) = self.level() {
+            pairs.push(format!("level={}", level));
+        }
+        match self {
+            Identifier::Sentinel2Product(identifiers::sentinel2::Product {
+                tile_number, ..
+            })
+            | Identifier::HlsProduct(identifiers::hls::Product { tile_number, .. }) => {
+                pairs.push(format!("tile=T{}", tile_number));
+            }
+            Identifier::Sentinel3Product(identifiers::sentinel3::Product {
+                instance_id: identifiers::sentinel3::InstanceId::Tile { tile_identifier },
+                ..
+            }) => pairs.push(format!("tile={}", tile_identifier)),
+            Identifier::LandsatSceneId(identifiers::landsat::SceneId {
+                wrs_path, wrs_row, ..
+            })
+            | Identifier::LandsatProduct(identifiers::landsat::Product {
+                wrs_path, wrs_row, ..
+            }) => {
+                pairs.push(format!("path={:03}", wrs_path));
+                pairs.push(format!("row={:03}", wrs_row));
+            }
+            Identifier::ModisProduct(p) => {
+                pairs.push(format!("h={:02}", p.horizontal_tile));
+                pairs.push(format!("v={:02}", p.vertical_tile));
+            }
+            _ => {}
+        }
+        if let Some(orbit) = self.relative_orbit() {
+            pairs.push(format!("orbit={}", orbit));
+        }
+        pairs.push(format!("date={}", self.start_datetime().date()));
+        pairs.join(" ")
+    }
+
+    /// processing level as used in the identifier
+    fn level(&self) -> Option<String> {
+        match self {
+            Identifier::Sentinel1Product(p) => Some(p.processing_level.code().to_string()),
+            Identifier::Sentinel2Product(p) => Some(p.product_level.code().to_string()),
+            Identifier::Sentinel2LongProduct(p) => Some(p.product_level.code().to_string()),
+            Identifier::Sentinel3Product(p) => p.processing_level.map(|l| l.to_string()),
+            Identifier::Sentinel5pProduct(p) => Some(format!("L{}", p.product_level)),
+            Identifier::LandsatProduct(p) => Some(p.processing_level.code().to_string()),
+            Identifier::PlanetScene(s) => s.product_type.clone(),
+            Identifier::Sentinel1Dataset(_)
+            | Identifier::LandsatSceneId(_)
+            | Identifier::HlsProduct(_)
+            | Identifier::ModisProduct(_) => None,
+        }
+    }
+
+    /// stable spatial string for grouping, regardless of the mission
+    ///
+    /// This is the tile for Sentinel-2, HLS and tiled Sentinel-3 products, the WRS path and row
+    /// as `PPP_RRR` for Landsat, the sinusoidal grid tile as `hHH_vVV` for MODIS and the
+    /// relative orbit for Sentinel-1. Unlike the spatial key of [`Identifier::to_csv_row`]
+    /// the parts are separated by underscores.
+    pub fn spatial_str(&self) -> Option<String> {
+        use identifiers::sentinel3::InstanceId;
+
+        match self {
+            Identifier::Sentinel1Product(p) => Some(format!("{:03}", p.relative_orbit())),
+            Identifier::Sentinel1Dataset(ds) => Some(format!("{:03}", ds.relative_orbit())),
+            Identifier::Sentinel2Product(p) => Some(p.tile_number.clone()),
+            Identifier::Sentinel3Product(p) => match &p.instance_id {
+                InstanceId::Tile { tile_identifier } => Some(tile_identifier.clone()),
+                _ => None,
+            },
+            Identifier::LandsatSceneId(identifiers::landsat::SceneId {
+                wrs_path, wrs_row, ..
+            })
+            | Identifier::LandsatProduct(identifiers::landsat::Product {
+                wrs_path, wrs_row, ..
+            }) => Some(format!("{:03}_{:03}", wrs_path, wrs_row)),
+            Identifier::HlsProduct(p) => Some(p.tile_number.clone()),
+            Identifier::ModisProduct(p) => {
+                Some(format!("h{:02}_v{:02}", p.horizontal_tile, p.vertical_tile))
+            }
+            Identifier::Sentinel2LongProduct(_)
+            | Identifier::Sentinel5pProduct(_)
+            | Identifier::PlanetScene(_) => None,
+        }
+    }
+
+    /// key for sorting by mission, then sensing start
+    pub fn sort_key(&self) -> SortKey {
+        SortKey(self.mission(), self.start_datetime(), self.to_string())
+    }
+
+    /// nominal ground sample distance in meters, where applicable
+    ///
+    /// For Sentinel-2 this is the resolution of the highest resolved bands.
+    pub fn nominal_gsd(&self) -> Option<u16> {
+        match self {
+            Identifier::Sentinel2Product(_) | Identifier::Sentinel2LongProduct(_) => Some(10),
+            Identifier::LandsatSceneId(identifiers::landsat::SceneId { sensor, .. })
+            | Identifier::LandsatProduct(identifiers::landsat::Product { sensor, .. }) => {
+                Some(sensor.gsd_meters())
+            }
+            Identifier::HlsProduct(_) => Some(30),
+            Identifier::Sentinel1Product(_)
+            | Identifier::Sentinel1Dataset(_)
+            | Identifier::Sentinel3Product(_)
+            | Identifier::Sentinel5pProduct(_)
+            | Identifier::ModisProduct(_)
+            | Identifier::PlanetScene(_) => None,
+        }
+    }
+
+    /// key composed of the mission and the instrument, for example `sentinel-2/msi`
+    ///
+    /// Suitable for partitioning storage by mission and instrument.
+    pub fn mission_instrument_key(&self) -> String {
+        format!("{}/{}", self.mission().slug(), self.instrument())
+    }
+
+    /// conventional relative path to store the product at
+    ///
+    /// Requires the `std` feature.
+    ///
+    /// Landsat products are organized by WRS path, row and year
+    /// (`landsat/039/022/2013/<identifier>`), Sentinel-2 products by date and tile
+    /// (`sentinel-2/2017/01/05/T53NMJ/<identifier>`). All other products are organized by
+    /// mission and date.
+    #[cfg(feature = "std")]
+    pub fn suggested_path(&self) -> PathBuf {
+        let start = self.start_datetime();
+        let mut path = PathBuf::new();
+        match self {
+            Identifier::LandsatSceneId(identifiers::landsat::SceneId {
+                wrs_path, wrs_row, ..
+            })
+            | Identifier::LandsatProduct(identifiers::landsat::Product {
+                wrs_path, wrs_row, ..
+            }) => {
+                path.push("landsat");
+                path.push(format!("{:03}", wrs_path));
+                path.push(format!("{:03}", wrs_row));
+                path.push(start.format("%Y").to_string());
+            }
+            _ => {
+                path.push(self.mission().slug());
+                path.push(start.format("%Y").to_string());
+                path.push(start.format("%m").to_string());
+                path.push(start.format("%d").to_string());
+                if let Identifier::Sentinel2Product(p) = self {
+                    path.push(format!("T{}", p.tile_number));
+                }
+            }
+        }
+        path.push(self.to_string());
+        path
+    }
+
+    /// sensing start datetime converted from UTC to the local time of the given timezone
+    #[cfg(feature = "chrono-tz")]
+    pub fn local_datetime(&self, tz: chrono_tz::Tz) -> chrono::DateTime<chrono_tz::Tz> {
+        use chrono::TimeZone;
+        tz.from_utc_datetime(&self.start_datetime())
+    }
+}
+
+/// Key for sorting identifiers deterministically
+///
+/// Sorts by the mission - ordered by its [slug](Mission::slug) - then by the sensing start
+/// datetime, then by the canonical string of the identifier.
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+pub struct SortKey(pub Mission, pub NaiveDateTime, pub String);
+
+impl Ord for SortKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.0.slug(), self.1, &self.2).cmp(&(other.0.slug(), other.1, &other.2))
+    }
+}
+
+impl PartialOrd for SortKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// comparator ordering identifiers by their sensing start datetime, to be used with `sort_by`
+///
+/// Identifiers acquired at the same time are ordered by their [sort key](Identifier::sort_key).
+///
+/// ```rust
+/// use eo_identifiers::{acquisition_comparator, Identifier};
+///
+/// let mut ids = Identifier::parse_many_ok([
+///     "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443",
+///     "LC08_L2SP_140041_20130503_20190828_02_T1",
+/// ]);
+/// ids.sort_by(acquisition_comparator());
+/// assert_eq!(ids[0].to_string(), "LC08_L2SP_140041_20130503_20190828_02_T1");
+/// ```
+pub fn acquisition_comparator() -> impl Fn(&Identifier, &Identifier) -> Ordering {
+    |a, b| {
+        a.start_datetime()
+            .cmp(&b.start_datetime())
+            .then_with(|| a.sort_key().cmp(&b.sort_key()))
+    }
+}
+
+/// group identifiers by their mission and relative orbit
+///
+/// The numbering of relative orbits is specific to each mission, so equal numbers of
+/// different missions form separate groups. Identifiers without a relative orbit are
+/// skipped. Requires the `std` feature.
+#[cfg(feature = "std")]
+pub fn group_by_relative_orbit(ids: Vec<Identifier>) -> HashMap<(Mission, u32), Vec<Identifier>> {
+    let mut groups: HashMap<(Mission, u32), Vec<Identifier>> = HashMap::new();
+    for id in ids {
+        if let Some(relative_orbit) = id.relative_orbit() {
+            groups
+                .entry((id.mission(), relative_orbit))
+                .or_default()
+                .push(id);
+        }
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "std")]
+    use crate::group_by_relative_orbit;
+    use crate::{acquisition_comparator, Identifier, JulianDate, Mission, Timeliness};
+    use std::str::FromStr;
+
+    #[test]
+    fn display_roundtrip() {
+        for s in [
+            "S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237",
+            "s1a-iw-grd-vh-20221029t171425-20221029t171450-045660-0575ce-002",
+            "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443.SAFE",
+            "S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002",
+            "LC08_L2SP_140041_20130503_20190828_02_T1",
+            "LC80390222013076edc00",
+            "HLS.S30.T53NMJ.2017005T013442.v2.0",
+            "MOD09GA.A2023001.h18v04.061.2023003011222.hdf",
+            "S5P_OFFL_L2__NO2____20230101T005953_20230101T024123_21890_03_020400_20230102T170000",
+            "20210602_143854_1003_3B_AnalyticMS",
+            "20220101_151123_66_2426",
+            "S2A_OPER_PRD_MSIL1C_PDMC_20160113T172424_R051_V20160113T104035_20160113T104035.SAFE",
+        ] {
+            let ident = Identifier::from_str(s).unwrap();
+            assert_eq!(Identifier::from_str(&ident.to_string()).unwrap(), ident);
+        }
+    }
+
+    #[test]
+    fn display_is_canonical() {
+        let ident = Identifier::from_str("LC80390222013076edc00").unwrap();
+        assert_eq!(ident.to_string(), "LC80390222013076EDC00");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn group_identifiers_by_relative_orbit() {
+        let ids = [
+            "S2A_MSIL1C_20150803T101016_N0204_R022_T33UUP_20150803T101010",
+            "S2A_MSIL1C_20150806T102016_N0204_R065_T33UUP_20150806T102012",
+            "S2A_MSIL1C_20150813T101026_N0204_R022_T33UUP_20150813T101657",
+            "LC08_L2SP_140041_20130503_20190828_02_T1",
+        ]
+        .map(|s| Identifier::from_str(s).unwrap())
+        .to_vec();
+        let groups = group_by_relative_orbit(ids);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[&(Mission::Sentinel2, 22)].len(), 2);
+        assert_eq!(groups[&(Mission::Sentinel2, 65)].len(), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn group_by_relative_orbit_separates_missions() {
+        let ids = [
+            // absolute orbit 31078 is relative orbit 31 of S1A
+            "S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031078_039466_A237",
+            "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443",
+        ]
+        .map(|s| Identifier::from_str(s).unwrap())
+        .to_vec();
+        let groups = group_by_relative_orbit(ids);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[&(Mission::Sentinel1, 31)].len(), 1);
+        assert_eq!(groups[&(Mission::Sentinel2, 31)].len(), 1);
+    }
+
+    #[test]
+    fn julian_date() {
+        let ident = Identifier::from_str("LC80390222013076EDC00").unwrap();
+        assert_eq!(ident.day_of_year(), 76);
+        assert_eq!(ident.julian_string(), "2013076");
+
+        let ident =
+            Identifier::from_str("S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443")
+                .unwrap();
+        assert_eq!(ident.day_of_year(), 5);
+        assert_eq!(ident.julian_string(), "2017005");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn suggested_path() {
+        let ident = Identifier::from_str(
+            "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443.SAFE",
+        )
+        .unwrap();
+        let components: Vec<_> = ident
+            .suggested_path()
+            .iter()
+            .map(|c| c.to_string_lossy().to_string())
+            .collect();
+        assert_eq!(
+            components,
+            vec![
+                "sentinel-2",
+                "2017",
+                "01",
+                "05",
+                "T53NMJ",
+                "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443"
+            ]
+        );
+
+        let ident = Identifier::from_str("LC80390222013076EDC00").unwrap();
+        let components: Vec<_> = ident
+            .suggested_path()
+            .iter()
+            .map(|c| c.to_string_lossy().to_string())
+            .collect();
+        assert_eq!(
+            components,
+            vec!["landsat", "039", "022", "2013", "LC80390222013076EDC00"]
+        );
+
+        let ident = Identifier::from_str("MOD09GA.A2023001.h18v04.061.2023003011222.hdf").unwrap();
+        assert!(ident.suggested_path().starts_with("modis/2023/01/01"));
+    }
+
+    #[test]
+    fn sort_by_acquisition_comparator() {
+        let mut ids = [
+            "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443",
+            "S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237",
+            "HLS.S30.T53NMJ.2017005T013442.v2.0",
+            "LC08_L2SP_140041_20130503_20190828_02_T1",
+        ]
+        .map(|s| Identifier::from_str(s).unwrap());
+        ids.sort_by(acquisition_comparator());
+
+        let missions: Vec<_> = ids.iter().map(|id| id.mission()).collect();
+        assert_eq!(
+            missions,
+            vec![
+                Mission::Landsat8,
+                // same sensing start as the Sentinel-2 product, ordered by the sort key
+                Mission::Hls,
+                Mission::Sentinel2,
+                Mission::Sentinel1,
+            ]
+        );
+    }
+
+    #[test]
+    fn sort_by_sort_key() {
+        let mut ids = [
+            "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443",
+            "S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237",
+            "S2A_MSIL1C_20150803T101016_N0204_R022_T33UUP_20150803T101010",
+            "LC08_L2SP_140041_20130503_20190828_02_T1",
+            "HLS.S30.T53NMJ.2017005T013442.v2.0",
+        ]
+        .map(|s| Identifier::from_str(s).unwrap());
+        ids.sort_by_key(|id| id.sort_key());
+
+        let ordered: Vec<_> = ids
+            .iter()
+            .map(|id| (id.mission(), id.start_datetime().date().to_string()))
+            .collect();
+        assert_eq!(
+            ordered,
+            vec![
+                (Mission::Hls, "2017-01-05".to_string()),
+                (Mission::Landsat8, "2013-05-03".to_string()),
+                (Mission::Sentinel1, "2020-02-07".to_string()),
+                (Mission::Sentinel2, "2015-08-03".to_string()),
+                (Mission::Sentinel2, "2017-01-05".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn csv_row_matches_header() {
+        for s in [
+            "S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237",
+            "s1a-iw-grd-vh-20221029t171425-20221029t171450-045660-0575ce-002",
+            "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443",
+            "S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002",
+            "LC08_L2SP_140041_20130503_20190828_02_T1",
+            "LC80390222013076EDC00",
+            "HLS.S30.T53NMJ.2017005T013442.v2.0",
+            "MOD09GA.A2023001.h18v04.061.2023003011222.hdf",
+            "S5P_OFFL_L2__NO2____20230101T005953_20230101T024123_21890_03_020400_20230102T170000",
+            "20210602_143854_1003_3B_AnalyticMS",
+            "S2A_OPER_PRD_MSIL1C_PDMC_20160113T172424_R051_V20160113T104035_20160113T104035.SAFE",
+        ] {
+            let row = Identifier::from_str(s).unwrap().to_csv_row();
+            assert_eq!(row.len(), Identifier::csv_header().len());
+        }
+
+        let row = Identifier::from_str("LC08_L2SP_140041_20130503_20190828_02_T1")
+            .unwrap()
+            .to_csv_row();
+        assert_eq!(
+            row,
+            vec!["landsat-8", "2013-05-03T00:00:00Z", "L2SP", "140041", ""]
+        );
+    }
+
+    #[test]
+    fn no_fields_beyond_the_name() {
+        // cloud cover and sun elevation are part of the metadata, not of the name
+        let is_external = |key: &str| key.contains("cloud") || key.contains("sun");
+        assert!(!Identifier::csv_header().into_iter().any(is_external));
+
+        for s in [
+            "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443",
+            "LC08_L2SP_140041_20130503_20190828_02_T1",
+            "LC80390222013076EDC00",
+        ] {
+            let line = Identifier::from_str(s).unwrap().to_kv_line();
+            assert!(!line
+                .split(' ')
+                .filter_map(|kv| kv.split_once('=').map(|(k, _)| k))
+                .any(is_external));
+        }
+    }
+
+    #[test]
+    fn kv_line() {
+        let line = Identifier::from_str("LC08_L2SP_140041_20130503_20190828_02_T1")
+            .unwrap()
+            .to_kv_line();
+        assert_eq!(
+            line,
+            "mission=landsat-8 level=L2SP path=140 row=041 date=2013-05-03"
+        );
+
+        let line =
+            Identifier::from_str("S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443")
+                .unwrap()
+                .to_kv_line();
+        for key in [
+            "mission=sentinel-2",
+            "level=L1C",
+            "tile=T53NMJ",
+            "orbit=31",
+            "date=2017-01-05",
+        ] {
+            assert!(line.split(' ').any(|kv| kv == key), "{}", line);
+        }
+
+        let line = Identifier::from_str("MOD09GA.A2023001.h18v04.061.2023003011222.hdf")
+            .unwrap()
+            .to_kv_line();
+        assert_eq!(line, "mission=modis h=18 v=04 date=2023-01-01");
+
+        let line = Identifier::from_str(
+            "S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237",
+        )
+        .unwrap()
+        .to_kv_line();
+        for key in ["mission=sentinel-1", "level=1", "orbit=", "date=2020-02-07"] {
+            assert!(line.contains(key), "{}", line);
+        }
+
+        let line = Identifier::from_str("HLS.S30.T53NMJ.2017005T013442.v2.0")
+            .unwrap()
+            .to_kv_line();
+        assert!(!line.contains("level="));
+        assert!(line.contains("tile=T53NMJ"));
+        assert!(!line.contains("  "));
+    }
+
+    #[test]
+    fn mission_slug() {
+        for (mission, slug) in [
+            (Mission::Sentinel1, "sentinel-1"),
+            (Mission::Sentinel2, "sentinel-2"),
+            (Mission::Sentinel3, "sentinel-3"),
+            (Mission::Sentinel5p, "sentinel-5p"),
+            (Mission::Landsat1, "landsat-1"),
+            (Mission::Landsat2, "landsat-2"),
+            (Mission::Landsat3, "landsat-3"),
+            (Mission::Landsat4, "landsat-4"),
+            (Mission::Landsat5, "landsat-5"),
+            (Mission::Landsat6, "landsat-6"),
+            (Mission::Landsat7, "landsat-7"),
+            (Mission::Landsat8, "landsat-8"),
+            (Mission::Landsat9, "landsat-9"),
+            (Mission::Hls, "hls"),
+            (Mission::Modis, "modis"),
+            (Mission::Planet, "planet"),
+        ] {
+            assert_eq!(mission.slug(), slug);
+        }
+    }
+
+    #[test]
+    fn spatial_str() {
+        let spatial_str = |s: &str| Identifier::from_str(s).unwrap().spatial_str();
+
+        assert_eq!(
+            spatial_str("S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237")
+                .as_deref(),
+            Some("095")
+        );
+        assert_eq!(
+            spatial_str("S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443").as_deref(),
+            Some("53NMJ")
+        );
+        assert_eq!(
+            spatial_str("LC08_L2SP_140041_20130503_20190828_02_T1").as_deref(),
+            Some("140_041")
+        );
+        assert_eq!(
+            spatial_str("LC80390222013076EDC00").as_deref(),
+            Some("039_022")
+        );
+        assert_eq!(
+            spatial_str("HLS.S30.T53NMJ.2017005T013442.v2.0").as_deref(),
+            Some("53NMJ")
+        );
+        assert_eq!(
+            spatial_str("MOD09GA.A2023001.h18v04.061.2023003011222.hdf").as_deref(),
+            Some("h18_v04")
+        );
+        assert_eq!(
+            spatial_str("S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002"),
+            None
+        );
+    }
+
+    #[test]
+    fn timeliness_sentinel3() {
+        let ident = Identifier::from_str("S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002").unwrap();
+        assert_eq!(ident.timeliness(), Some(Timeliness::NTC));
+        let ident = Identifier::from_str("S3B_SL_2_FRP____20221030T181034_20221030T181334_20221030T201327_0179_072_241_0720_PS2_O_NR_004").unwrap();
+        assert_eq!(ident.timeliness(), Some(Timeliness::NRT));
+    }
+
+    #[test]
+    fn timeliness_sentinel5p() {
+        let timeliness = |s: &str| Identifier::from_str(s).unwrap().timeliness();
+
+        assert_eq!(timeliness("S5P_NRTI_L2__HCHO___20220204T003219_20220204T003719_22340_02_020201_20220204T013955"), Some(Timeliness::NRT));
+        assert_eq!(timeliness("S5P_OFFL_L2__NO2____20230101T005953_20230101T024123_21890_03_020400_20230102T170000"), Some(Timeliness::OFFL));
+        assert_eq!(timeliness("S5P_RPRO_L2__SO2____20180819T090430_20180819T104736_04398_01_010105_20190218T225114"), Some(Timeliness::RPRO));
+    }
+
+    #[test]
+    fn is_reprocessed() {
+        let reprocessed = |s: &str| Identifier::from_str(s).unwrap().is_reprocessed();
+
+        assert_eq!(reprocessed("S3A_OL_1_EFR____20160516T180025_20160516T180325_20180209T163150_0179_004_155_3060_LR2_R_NT_002"), Some(true));
+        assert_eq!(reprocessed("S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002"), Some(false));
+        assert_eq!(
+            reprocessed("LC08_L2SP_140041_20130503_20190828_02_T1"),
+            Some(true)
+        );
+        assert_eq!(
+            reprocessed("LC08_L1GT_029030_20151209_20151210_01_RT"),
+            Some(false)
+        );
+        assert_eq!(
+            reprocessed("S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443"),
+            None
+        );
+    }
+
+    #[test]
+    fn processing_recency() {
+        let recency = |s: &str| Identifier::from_str(s).unwrap().processing_recency();
+
+        assert!(
+            recency("S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002")
+                > recency("S3A_OL_1_EFR____20220801T210143_20220801T210443_20220801T224512_0179_088_157_1800_PS1_O_NR_002")
+        );
+        assert!(
+            recency("LC08_L1TP_029030_20151209_20200908_02_T1")
+                > recency("LC08_L1TP_029030_20151209_20170401_01_T1")
+        );
+        assert!(
+            recency("S2A_MSIL1C_20170105T013442_N0500_R031_T53NMJ_20230301T120000")
+                > recency("S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443")
+        );
+        assert_eq!(
+            recency("S2A_MSIL2A_20170105T013442_N0204_R031_T53NMJ"),
+            None
+        );
+    }
+
+    #[test]
+    fn mission_instrument_key() {
+        for (s, key) in [
+            (
+                "S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237",
+                "sentinel-1/c-sar",
+            ),
+            (
+                "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443",
+                "sentinel-2/msi",
+            ),
+            (
+                "S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002",
+                "sentinel-3/olci",
+            ),
+            ("LC08_L2SP_140041_20130503_20190828_02_T1", "landsat-8/oli-tirs"),
+            ("LE71810402006015ASN00", "landsat-7/etm-plus"),
+        ] {
+            assert_eq!(Identifier::from_str(s).unwrap().mission_instrument_key(), key);
+        }
+    }
+
+    #[test]
+    fn capabilities() {
+        let caps = |s: &str| Identifier::from_str(s).unwrap().capabilities();
+
+        let msi = caps("S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443").unwrap();
+        assert_eq!(msi.spectral_bands, 13);
+        assert!(!msi.has_thermal_bands);
+
+        let oli_tirs = caps("LC08_L2SP_140041_20130503_20190828_02_T1").unwrap();
+        assert_eq!(oli_tirs.spectral_bands, 11);
+        assert!(oli_tirs.has_thermal_bands);
+        assert_eq!(oli_tirs.swath_width_km, 185);
+
+        let olci = caps("S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002").unwrap();
+        assert_eq!(olci.spectral_bands, 21);
+
+        assert!(caps("S3B_SL_2_FRP____20221030T181034_20221030T181334_20221030T201327_0179_072_241_0720_PS2_O_NR_004").unwrap().has_thermal_bands);
+        assert_eq!(
+            caps("S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237"),
+            None
+        );
+    }
+
+    #[test]
+    fn nominal_gsd() {
+        let gsd = |s: &str| Identifier::from_str(s).unwrap().nominal_gsd();
+        assert_eq!(gsd("LC08_L2SP_140041_20130503_20190828_02_T1"), Some(30));
+        assert_eq!(gsd("LM10440341972236AAA04"), Some(60));
+        assert_eq!(gsd("HLS.S30.T53NMJ.2017005T013442.v2.0"), Some(30));
+        assert_eq!(
+            gsd("S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237"),
+            None
+        );
+    }
+
+    #[test]
+    fn stac_datetime_strings() {
+        let ident =
+            Identifier::from_str("S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443")
+                .unwrap();
+        assert_eq!(
+            ident.stac_datetime_strings(),
+            ("2017-01-05T01:34:42Z".to_string(), None)
+        );
+
+        let ident = Identifier::from_str(
+            "S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237",
+        )
+        .unwrap();
+        assert_eq!(
+            ident.stac_datetime_strings(),
+            (
+                "2020-02-07T05:18:36Z".to_string(),
+                Some("2020-02-07T05:19:01Z".to_string())
+            )
+        );
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn local_datetime() {
+        let ident =
+            Identifier::from_str("S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443")
+                .unwrap();
+        assert_eq!(
+            ident.local_datetime(chrono_tz::Asia::Tokyo).to_rfc3339(),
+            "2017-01-05T10:34:42+09:00"
+        );
+    }
+
+    #[test]
+    fn timeliness_not_encoded() {
+        for s in [
+            "S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237",
+            "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443",
+            "LC08_L2SP_140041_20130503_20190828_02_T1",
+        ] {
+            assert_eq!(Identifier::from_str(s).unwrap().timeliness(), None);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use crate::Identifier;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    use std::str::FromStr;
+
+    fn hash_of(ident: &Identifier) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        ident.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn serde_roundtrip_keeps_eq_and_hash() {
+        for s in [
+            "S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237",
+            "s1a-iw-grd-vh-20221029t171425-20221029t171450-045660-0575ce-002",
+            "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443",
+            "S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002",
+            "LC08_L2SP_140041_20130503_20190828_02_T1",
+            "LC80390222013076EDC00",
+            "HLS.S30.T53NMJ.2017005T013442.v2.0",
+            "MOD09GA.A2023001.h18v04.061.2023003011222.hdf",
+            "S5P_OFFL_L2__NO2____20230101T005953_20230101T024123_21890_03_020400_20230102T170000",
+            "20210602_143854_1003_3B_AnalyticMS",
+            "S2A_OPER_PRD_MSIL1C_PDMC_20160113T172424_R051_V20160113T104035_20160113T104035.SAFE",
+        ] {
+            let ident = Identifier::from_str(s).unwrap();
+            let json = serde_json::to_string(&ident).unwrap();
+            let roundtripped: Identifier = serde_json::from_str(&json).unwrap();
+            assert_eq!(ident, roundtripped);
+            assert_eq!(hash_of(&ident), hash_of(&roundtripped));
+        }
+    }
+}
+
+/// run with `cargo test --no-default-features` to cover the parsers without `std`
+#[cfg(all(test, not(feature = "std")))]
+mod no_std_tests {
+    use crate::{Identifier, Mission};
+    use alloc::string::ToString;
+    use core::str::FromStr;
+
+    #[test]
+    fn parse_without_std() {
+        for (s, mission) in [
+            (
+                "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443",
+                Mission::Sentinel2,
+            ),
+            ("LC80390222013076EDC00", Mission::Landsat8),
+            ("MOD09GA.A2023001.h18v04.061.2023003011222", Mission::Modis),
+        ] {
+            let ident = Identifier::from_str(s).unwrap();
+            assert_eq!(ident.mission(), mission);
+            assert_eq!(ident.to_string(), s);
         }
+        assert!(Identifier::from_str("S2A_MSIL1C_2017").is_err());
     }
 }
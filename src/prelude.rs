@@ -0,0 +1,25 @@
+//! Re-exports of the commonly used traits and types
+//!
+//! # Example
+//!
+//! ```rust
+//! use eo_identifiers::prelude::*;
+//!
+//! let ident = Identifier::from_str("LC08_L2SP_140041_20130503_20190828_02_T1").unwrap();
+//! assert_eq!(ident.mission().name(), "Landsat 8");
+//!
+//! let product = LandsatProduct::from_str("LC08_L2SP_140041_20130503_20190828_02_T1").unwrap();
+//! assert_eq!(product.collection_category.unwrap().name_long(), "Tier 1");
+//! ```
+pub use crate::identifiers::hls::Product as HlsProduct;
+pub use crate::identifiers::landsat::{Product as LandsatProduct, SceneId as LandsatSceneId};
+pub use crate::identifiers::modis::Product as ModisProduct;
+pub use crate::identifiers::planet::Scene as PlanetScene;
+pub use crate::identifiers::sentinel1::{Dataset as Sentinel1Dataset, Product as Sentinel1Product};
+pub use crate::identifiers::sentinel2::{
+    LongProduct as Sentinel2LongProduct, Product as Sentinel2Product,
+};
+pub use crate::identifiers::sentinel3::Product as Sentinel3Product;
+pub use crate::identifiers::sentinel5p::Product as Sentinel5pProduct;
+pub use crate::{Identifier, JulianDate, Mission, Name, NameLong, ParseError};
+pub use core::str::FromStr;
@@ -0,0 +1,63 @@
+//! (De)serialize identifiers as their string representation
+//!
+//! To be used with the `with` attribute of serde:
+//!
+//! ```rust
+//! use eo_identifiers::identifiers::landsat::SceneId;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Scene {
+//!     #[serde(with = "eo_identifiers::serde_str")]
+//!     id: SceneId,
+//! }
+//!
+//! let scene: Scene = serde_json::from_str(r#"{"id": "LC80390222013076EDC00"}"#).unwrap();
+//! assert_eq!(scene.id.wrs_path, 39);
+//! ```
+use alloc::string::String;
+use core::fmt::Display;
+use core::str::FromStr;
+use serde::{de, Deserialize, Deserializer, Serializer};
+
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Display,
+    S: Serializer,
+{
+    serializer.collect_str(value)
+}
+
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr,
+    T::Err: Display,
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::identifiers::landsat::SceneId;
+    use chrono::NaiveDate;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Scene {
+        #[serde(with = "crate::serde_str")]
+        id: SceneId,
+    }
+
+    #[test]
+    fn landsat_scene_id_roundtrip() {
+        let json = r#"{"id":"LC80390222013076EDC00"}"#;
+        let scene: Scene = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            scene.id.acquire_date,
+            NaiveDate::from_ymd_opt(2013, 3, 17).unwrap()
+        );
+        assert_eq!(serde_json::to_string(&scene).unwrap(), json);
+    }
+}
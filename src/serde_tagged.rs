@@ -0,0 +1,151 @@
+//! (De)serialize identifiers internally tagged
+//!
+//! The derived serde implementation of [`Identifier`] is externally tagged
+//! (`{"Sentinel2Product": {...}}`). This module serializes the variant into a `type` field
+//! next to the fields of the product instead (`{"type": "Sentinel2Product", ...}`), as
+//! expected by some catalog schemas. To be used with the `with` attribute of serde:
+//!
+//! ```rust
+//! use eo_identifiers::Identifier;
+//! use serde::{Deserialize, Serialize};
+//! use std::str::FromStr;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Item {
+//!     #[serde(with = "eo_identifiers::serde_tagged")]
+//!     identifier: Identifier,
+//! }
+//!
+//! let item = Item {
+//!     identifier: Identifier::from_str("LC08_L2SP_140041_20130503_20190828_02_T1").unwrap(),
+//! };
+//! let value = serde_json::to_value(&item).unwrap();
+//! assert_eq!(value["identifier"]["type"], "LandsatProduct");
+//! assert_eq!(value["identifier"]["wrs_path"], 140);
+//! ```
+use crate::identifiers::{
+    hls, landsat, modis, planet, sentinel1, sentinel2, sentinel3, sentinel5p,
+};
+use crate::Identifier;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize)]
+#[serde(tag = "type")]
+enum TaggedRef<'a> {
+    Sentinel1Product(&'a sentinel1::Product),
+    Sentinel1Dataset(&'a sentinel1::Dataset),
+    Sentinel2Product(&'a sentinel2::Product),
+    Sentinel2LongProduct(&'a sentinel2::LongProduct),
+    Sentinel3Product(&'a sentinel3::Product),
+    Sentinel5pProduct(&'a sentinel5p::Product),
+    LandsatSceneId(&'a landsat::SceneId),
+    LandsatProduct(&'a landsat::Product),
+    HlsProduct(&'a hls::Product),
+    ModisProduct(&'a modis::Product),
+    PlanetScene(&'a planet::Scene),
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type")]
+enum Tagged {
+    Sentinel1Product(sentinel1::Product),
+    Sentinel1Dataset(sentinel1::Dataset),
+    Sentinel2Product(sentinel2::Product),
+    Sentinel2LongProduct(sentinel2::LongProduct),
+    Sentinel3Product(sentinel3::Product),
+    Sentinel5pProduct(sentinel5p::Product),
+    LandsatSceneId(landsat::SceneId),
+    LandsatProduct(landsat::Product),
+    HlsProduct(hls::Product),
+    ModisProduct(modis::Product),
+    PlanetScene(planet::Scene),
+}
+
+pub fn serialize<S>(identifier: &Identifier, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let tagged = match identifier {
+        Identifier::Sentinel1Product(p) => TaggedRef::Sentinel1Product(p),
+        Identifier::Sentinel1Dataset(ds) => TaggedRef::Sentinel1Dataset(ds),
+        Identifier::Sentinel2Product(p) => TaggedRef::Sentinel2Product(p),
+        Identifier::Sentinel2LongProduct(p) => TaggedRef::Sentinel2LongProduct(p),
+        Identifier::Sentinel3Product(p) => TaggedRef::Sentinel3Product(p),
+        Identifier::Sentinel5pProduct(p) => TaggedRef::Sentinel5pProduct(p),
+        Identifier::LandsatSceneId(s) => TaggedRef::LandsatSceneId(s),
+        Identifier::LandsatProduct(p) => TaggedRef::LandsatProduct(p),
+        Identifier::HlsProduct(p) => TaggedRef::HlsProduct(p),
+        Identifier::ModisProduct(p) => TaggedRef::ModisProduct(p),
+        Identifier::PlanetScene(s) => TaggedRef::PlanetScene(s),
+    };
+    tagged.serialize(serializer)
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Identifier, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match Tagged::deserialize(deserializer)? {
+        Tagged::Sentinel1Product(p) => p.into(),
+        Tagged::Sentinel1Dataset(ds) => ds.into(),
+        Tagged::Sentinel2Product(p) => p.into(),
+        Tagged::Sentinel2LongProduct(p) => p.into(),
+        Tagged::Sentinel3Product(p) => p.into(),
+        Tagged::Sentinel5pProduct(p) => p.into(),
+        Tagged::LandsatSceneId(s) => s.into(),
+        Tagged::LandsatProduct(p) => p.into(),
+        Tagged::HlsProduct(p) => p.into(),
+        Tagged::ModisProduct(p) => p.into(),
+        Tagged::PlanetScene(s) => s.into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Identifier;
+    use serde::{Deserialize, Serialize};
+    use std::str::FromStr;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Item {
+        #[serde(with = "crate::serde_tagged")]
+        identifier: Identifier,
+    }
+
+    #[test]
+    fn internally_tagged_output() {
+        let item = Item {
+            identifier: Identifier::from_str(
+                "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443",
+            )
+            .unwrap(),
+        };
+        let value = serde_json::to_value(&item).unwrap();
+        assert_eq!(value["identifier"]["type"], "Sentinel2Product");
+        assert_eq!(value["identifier"]["tile_number"], "53NMJ");
+        assert_eq!(value["identifier"]["relative_orbit_number"], 31);
+    }
+
+    #[test]
+    fn internally_tagged_roundtrip() {
+        for s in [
+            "S1A_IW_GRDH_1SDV_20200207T051836_20200207T051901_031142_039466_A237",
+            "s1a-iw-grd-vh-20221029t171425-20221029t171450-045660-0575ce-002",
+            "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443",
+            "S3A_OL_1_EFR____20220801T210143_20220801T210443_20220803T023357_0179_088_157_1800_MAR_O_NT_002",
+            "LC08_L2SP_140041_20130503_20190828_02_T1",
+            "LC80390222013076EDC00",
+            "HLS.S30.T53NMJ.2017005T013442.v2.0",
+            "MOD09GA.A2023001.h18v04.061.2023003011222.hdf",
+            "S5P_OFFL_L2__NO2____20230101T005953_20230101T024123_21890_03_020400_20230102T170000",
+            "20210602_143854_1003_3B_AnalyticMS",
+            "S2A_OPER_PRD_MSIL1C_PDMC_20160113T172424_R051_V20160113T104035_20160113T104035",
+        ] {
+            let item = Item {
+                identifier: Identifier::from_str(s).unwrap(),
+            };
+            let json = serde_json::to_string(&item).unwrap();
+            assert_eq!(serde_json::from_str::<Item>(&json).unwrap(), item);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{Identifier, ParseError};
+use core::str::FromStr;
+use serde_json::Value;
+
+impl Identifier {
+    /// Parse the `id` of a [STAC item](https://github.com/radiantearth/stac-spec/blob/master/item-spec/item-spec.md)
+    pub fn from_stac_item(value: &Value) -> Result<Identifier, ParseError> {
+        parse_str_value(value.get("id"))
+    }
+
+    /// Parse the given property of the `properties` of a STAC item
+    ///
+    /// Useful for catalogs where the item `id` does not follow the naming convention
+    /// of the product, but the original name is kept in a property.
+    pub fn from_stac_item_property(
+        value: &Value,
+        property: &str,
+    ) -> Result<Identifier, ParseError> {
+        parse_str_value(
+            value
+                .get("properties")
+                .and_then(|properties| properties.get(property)),
+        )
+    }
+}
+
+fn parse_str_value(value: Option<&Value>) -> Result<Identifier, ParseError> {
+    value
+        .and_then(Value::as_str)
+        .ok_or(ParseError::NoIdentifier)
+        .and_then(Identifier::from_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Identifier, ParseError};
+    use serde_json::json;
+
+    #[test]
+    fn from_stac_item() {
+        let item = json!({
+            "type": "Feature",
+            "stac_version": "1.0.0",
+            "id": "S2A_MSIL1C_20170105T013442_N0204_R031_T53NMJ_20170105T013443",
+            "properties": {
+                "datetime": "2017-01-05T01:34:42Z"
+            }
+        });
+        let ident = Identifier::from_stac_item(&item).unwrap();
+        assert!(matches!(ident, Identifier::Sentinel2Product(_)));
+    }
+
+    #[test]
+    fn from_stac_item_property() {
+        let item = json!({
+            "type": "Feature",
+            "stac_version": "1.0.0",
+            "id": "some-item",
+            "properties": {
+                "landsat:product_id": "LC08_L2SP_140041_20130503_20190828_02_T1"
+            }
+        });
+        assert!(Identifier::from_stac_item(&item).is_err());
+        let ident = Identifier::from_stac_item_property(&item, "landsat:product_id").unwrap();
+        assert!(matches!(ident, Identifier::LandsatProduct(_)));
+    }
+
+    #[test]
+    fn from_stac_item_without_id() {
+        let item = json!({ "type": "Feature" });
+        assert!(matches!(
+            Identifier::from_stac_item(&item),
+            Err(ParseError::NoIdentifier)
+        ));
+    }
+}